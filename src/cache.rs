@@ -0,0 +1,212 @@
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::loxapp3::{LoxoneApp3, LoxoneUUID};
+use crate::ws::{LoxAPP3RequestError, LoxoneFile, RequestError, WebSocket};
+
+/// On-disk cache of the LoxAPP3 structure file.
+///
+/// The cached file is only replaced when the Miniserver reports a different structure file version.
+pub struct StructureCache {
+    path: PathBuf,
+    modifications: Option<LoxoneUUID>,
+}
+
+#[derive(Error, Debug)]
+pub enum StructureCacheError {
+    #[error("io error")]
+    Io(#[from] io::Error),
+    #[error("invalid json")]
+    JsonDeserialize(#[from] serde_json::Error),
+    #[error("version request error")]
+    VersionRequest(#[from] RequestError),
+    #[error("structure file request error")]
+    LoxAPP3Request(#[from] LoxAPP3RequestError),
+}
+
+/// On-disk cache of files downloaded from the Miniserver, such as icons and images.
+///
+/// Files are stored below the cache directory under their Miniserver path and never expire.
+pub struct IconCache {
+    dir: PathBuf,
+}
+
+#[derive(Error, Debug)]
+pub enum IconCacheError {
+    #[error("io error")]
+    Io(#[from] io::Error),
+    #[error("file request error")]
+    FileRequest(#[from] RequestError),
+    #[error("invalid file path {0:?}")]
+    InvalidPath(String),
+}
+
+impl StructureCache {
+    /// Creates a cache storing the structure file at the given `path`.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into(), modifications: None }
+    }
+
+    /// Returns the path of the cached structure file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the structure file, downloading it only if the cached one is missing or outdated.
+    pub async fn load(&mut self, ws: &mut WebSocket) -> Result<LoxoneApp3, StructureCacheError> {
+        let version = ws.get_loxapp3_timestamp().await?;
+        let loxapp3 = match self.read().await {
+            Some(loxapp3) if loxapp3.last_modified == version => loxapp3,
+            _ => self.download(ws).await?,
+        };
+        self.modifications = Some(loxapp3.global_states.modifications.clone());
+        ws.set_modifications_state(loxapp3.global_states.modifications.clone());
+        Ok(loxapp3)
+    }
+
+    /// Returns `true` if the given state UUID is the `modifications` global state.
+    ///
+    /// Any update of this state received on the status update stream means the configuration has been
+    /// re-deployed and the structure file should be reloaded with [`StructureCache::load`].
+    pub fn is_modification(&self, uuid: &LoxoneUUID) -> bool {
+        self.modifications.as_ref() == Some(uuid)
+    }
+
+    async fn read(&self) -> Option<LoxoneApp3> {
+        let loxapp3_json = tokio::fs::read(&self.path).await.ok()?;
+        serde_json::from_slice(&loxapp3_json).ok()
+    }
+
+    async fn download(&self, ws: &mut WebSocket) -> Result<LoxoneApp3, StructureCacheError> {
+        let loxapp3_json: serde_json::Value = ws.get_loxapp3().await?;
+        tokio::fs::write(&self.path, serde_json::to_vec(&loxapp3_json)?).await?;
+        Ok(serde_json::from_value(loxapp3_json)?)
+    }
+}
+
+impl IconCache {
+    /// Creates a cache storing files below the given directory.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the file at `path`, downloading it only if it isn't cached yet.
+    pub async fn get(&self, ws: &mut WebSocket, path: &str) -> Result<LoxoneFile, IconCacheError> {
+        let cache_path = self.cache_path(path)?;
+        if let Ok(data) = tokio::fs::read(&cache_path).await {
+            return Ok(LoxoneFile::from(data));
+        }
+        let file = ws.get_file(path).await?;
+        if let Some(parent) = cache_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&cache_path, &file.data).await?;
+        Ok(file)
+    }
+
+    fn cache_path(&self, path: &str) -> Result<PathBuf, IconCacheError> {
+        let relative = Path::new(path.trim_start_matches('/'));
+        match relative.components().all(|component| matches!(component, Component::Normal(_))) {
+            true if relative.components().next().is_some() => Ok(self.dir.join(relative)),
+            _ => Err(IconCacheError::InvalidPath(path.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "mock")]
+    use crate::mock::MockMiniserver;
+
+    #[cfg(feature = "mock")]
+    const LOXAPP3: &str = include_str!("../tests/fixtures/LoxAPP3.json");
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("loxone-cache-{}-{}", std::process::id(), name))
+    }
+
+    #[cfg(feature = "mock")]
+    async fn connect(miniserver: &MockMiniserver) -> WebSocket {
+        let (ws, _resp, _rx, recv_loop) = WebSocket::connect(miniserver.url()).await.unwrap();
+        tokio::spawn(recv_loop);
+        ws
+    }
+
+    #[cfg(feature = "mock")]
+    async fn load(name: &str, cached: &[u8]) -> (LoxoneApp3, LoxoneApp3) {
+        let miniserver = MockMiniserver::start(serde_json::from_str(LOXAPP3).unwrap(), "admin", "secret").await.unwrap();
+        let mut ws = connect(&miniserver).await;
+        let path = temp_path(name);
+        tokio::fs::write(&path, cached).await.unwrap();
+        let mut cache = StructureCache::new(&path);
+        let loxapp3 = cache.load(&mut ws).await.unwrap();
+        let stored = serde_json::from_slice(&tokio::fs::read(&path).await.unwrap()).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(cache.is_modification(&loxapp3.global_states.modifications));
+        (loxapp3, stored)
+    }
+
+    #[cfg(feature = "mock")]
+    fn cached_structure(last_modified: &str) -> Vec<u8> {
+        let mut loxapp3: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+        loxapp3["lastModified"] = serde_json::Value::from(last_modified);
+        loxapp3["msInfo"]["msName"] = serde_json::Value::from("Cached");
+        serde_json::to_vec(&loxapp3).unwrap()
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn loads_cached_structure_of_same_version() {
+        let (loxapp3, stored) = load("hit.json", &cached_structure("2026-09-14 18:22:37")).await;
+        assert_eq!(loxapp3.ms_info.ms_name, "Cached");
+        assert_eq!(stored.ms_info.ms_name, "Cached");
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn downloads_stale_structure() {
+        let (loxapp3, stored) = load("stale.json", &cached_structure("2026-08-01 09:00:00")).await;
+        assert_eq!(loxapp3.ms_info.ms_name, "Home");
+        assert_eq!((stored.ms_info.ms_name.as_str(), stored.last_modified.as_str()), ("Home", "2026-09-14 18:22:37"));
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn replaces_corrupt_structure() {
+        let (loxapp3, stored) = load("corrupt.json", b"{\"lastModified\": \"2026-09-14").await;
+        assert_eq!(loxapp3.ms_info.ms_name, "Home");
+        assert_eq!(stored.ms_info.ms_name, "Home");
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn downloads_icons_once() {
+        let miniserver = MockMiniserver::start(serde_json::from_str(LOXAPP3).unwrap(), "admin", "secret").await.unwrap();
+        miniserver.add_file("/icons/light.svg", b"<svg/>".to_vec());
+        let mut ws = connect(&miniserver).await;
+        let cache = IconCache::new(temp_path("icons"));
+        assert_eq!(cache.get(&mut ws, "/icons/light.svg").await.unwrap().data, b"<svg/>");
+        let cache_path = cache.dir().join("icons/light.svg");
+        assert_eq!(tokio::fs::read(&cache_path).await.unwrap(), b"<svg/>");
+        tokio::fs::write(&cache_path, b"<svg>cached</svg>").await.unwrap();
+        assert_eq!(cache.get(&mut ws, "/icons/light.svg").await.unwrap().data, b"<svg>cached</svg>");
+        let _ = std::fs::remove_dir_all(cache.dir());
+    }
+
+    #[test]
+    fn rejects_icon_paths_outside_cache_dir() {
+        let cache = IconCache::new(temp_path("icons"));
+        for path in &["../secret.svg", "/icons/../../etc/passwd", "./icons/light.svg", "", "/"] {
+            assert!(matches!(cache.cache_path(path), Err(IconCacheError::InvalidPath(invalid)) if invalid == *path), "{:?} accepted", path);
+        }
+        assert_eq!(cache.cache_path("/icons/light.svg").unwrap(), cache.dir().join("icons").join("light.svg"));
+    }
+}
@@ -0,0 +1,135 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::loxapp3::LoxoneState;
+
+/// Point in time as counted by the Miniserver, in seconds since 2009-01-01 00:00:00 Miniserver local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct LoxoneTimestamp(pub i64);
+
+impl LoxoneTimestamp {
+    /// Returns the Loxone epoch, 2009-01-01 00:00:00.
+    pub fn epoch() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2009, 1, 1).and_then(|date| date.and_hms_opt(0, 0, 0)).expect("valid Loxone epoch")
+    }
+
+    /// Creates a timestamp from a date time in Miniserver local time.
+    pub fn from_naive(local: NaiveDateTime) -> Self {
+        Self((local - Self::epoch()).num_seconds())
+    }
+
+    /// Creates a timestamp from a date time, given the Miniserver time zone offset.
+    pub fn from_datetime<Tz: TimeZone>(datetime: &DateTime<Tz>, offset: FixedOffset) -> Self {
+        Self::from_naive(datetime.with_timezone(&offset).naive_local())
+    }
+
+    /// Returns the current time, given the Miniserver time zone offset.
+    pub fn now(offset: FixedOffset) -> Self {
+        Self::from_datetime(&Utc::now(), offset)
+    }
+
+    /// Returns the date time in Miniserver local time.
+    pub fn naive_local(&self) -> NaiveDateTime {
+        Self::epoch() + Duration::seconds(self.0)
+    }
+
+    /// Returns the date time, given the Miniserver time zone offset.
+    pub fn with_offset(&self, offset: FixedOffset) -> DateTime<FixedOffset> {
+        DateTime::from_naive_utc_and_offset(self.naive_local() - offset, offset)
+    }
+}
+
+impl From<i32> for LoxoneTimestamp {
+    fn from(secs: i32) -> Self {
+        Self(i64::from(secs))
+    }
+}
+
+impl From<u32> for LoxoneTimestamp {
+    fn from(secs: u32) -> Self {
+        Self(i64::from(secs))
+    }
+}
+
+impl From<i64> for LoxoneTimestamp {
+    fn from(secs: i64) -> Self {
+        Self(secs)
+    }
+}
+
+impl fmt::Display for LoxoneTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.naive_local())
+    }
+}
+
+impl LoxoneState {
+    /// Returns the value of a state holding a Loxone timestamp, e.g. the `miniserverTime` global state.
+    pub fn as_timestamp(&self) -> Option<LoxoneTimestamp> {
+        match self {
+            Self::Value(secs) => Some(LoxoneTimestamp(*secs as i64)),
+            Self::Weather(_, last_update) => Some(LoxoneTimestamp::from(*last_update)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).and_then(|date| date.and_hms_opt(hour, minute, 0)).unwrap()
+    }
+
+    fn offset(hours: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600).unwrap()
+    }
+
+    #[test]
+    fn counts_from_loxone_epoch() {
+        assert_eq!(LoxoneTimestamp::epoch(), naive(2009, 1, 1, 0, 0));
+        assert_eq!(LoxoneTimestamp::from_naive(naive(2009, 1, 1, 0, 0)), LoxoneTimestamp(0));
+        assert_eq!(LoxoneTimestamp::from_naive(naive(2008, 12, 31, 23, 0)), LoxoneTimestamp(-3600));
+        assert_eq!(LoxoneTimestamp::from_naive(naive(2026, 7, 1, 12, 0)), LoxoneTimestamp(552_139_200));
+        assert_eq!(LoxoneTimestamp(552_139_200).naive_local(), naive(2026, 7, 1, 12, 0));
+        assert_eq!(LoxoneTimestamp(552_139_200).to_string(), "2026-07-01 12:00:00");
+    }
+
+    #[test]
+    fn applies_miniserver_offset() {
+        // The epoch is Miniserver local time, so it's an hour earlier in UTC for a UTC+1 Miniserver.
+        let epoch = LoxoneTimestamp(0).with_offset(offset(1));
+        assert_eq!(epoch.to_rfc3339(), "2009-01-01T00:00:00+01:00");
+        assert_eq!(epoch.naive_utc(), naive(2008, 12, 31, 23, 0));
+
+        let winter = LoxoneTimestamp(537_710_400).with_offset(offset(1));
+        assert_eq!(winter.to_rfc3339(), "2026-01-15T12:00:00+01:00");
+        assert_eq!(winter.naive_utc(), naive(2026, 1, 15, 11, 0));
+        assert_eq!(LoxoneTimestamp::from_datetime(&winter.with_timezone(&Utc), offset(1)), LoxoneTimestamp(537_710_400));
+    }
+
+    #[test]
+    fn applies_daylight_saving_offset() {
+        // 2026-03-29 03:00 CEST, right after the switch to daylight saving time in Central Europe.
+        let utc = Utc.with_ymd_and_hms(2026, 3, 29, 1, 0, 0).unwrap();
+        let timestamp = LoxoneTimestamp::from_datetime(&utc, offset(2));
+        assert_eq!(timestamp, LoxoneTimestamp(543_985_200));
+        assert_eq!(timestamp.with_offset(offset(2)), utc);
+        assert_eq!(timestamp.with_offset(offset(2)).to_rfc3339(), "2026-03-29T03:00:00+02:00");
+        // Interpreting the same local time with the standard time offset shifts it by an hour.
+        assert_eq!(timestamp.with_offset(offset(1)).naive_utc(), naive(2026, 3, 29, 2, 0));
+        assert_eq!(LoxoneTimestamp::from_datetime(&utc, offset(1)), LoxoneTimestamp(543_985_200 - 3600));
+    }
+
+    #[test]
+    fn reads_timestamp_states() {
+        assert_eq!(LoxoneState::Value(552_139_200.0).as_timestamp(), Some(LoxoneTimestamp(552_139_200)));
+        assert_eq!(LoxoneState::Value(552_139_200.9).as_timestamp(), Some(LoxoneTimestamp(552_139_200)));
+        assert_eq!(LoxoneState::Weather(Vec::new(), 537_710_400).as_timestamp(), Some(LoxoneTimestamp(537_710_400)));
+        assert_eq!(LoxoneState::Text(String::from("552139200"), String::new()).as_timestamp(), None);
+        assert_eq!(LoxoneState::Daytimer(Vec::new(), 0.0).as_timestamp(), None);
+    }
+}
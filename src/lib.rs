@@ -2,16 +2,47 @@
 
 pub mod loxapp3;
 
+mod cache;
+mod epoch;
+mod record;
+mod secret;
 mod ws;
 
+#[cfg(feature = "mock")]
+pub mod mock;
+
+pub use crate::cache::{IconCache, StructureCache};
+pub use crate::epoch::LoxoneTimestamp;
+pub use crate::record::{FrameDirection, RecordedFrame, RecordedPayload, ReplayEvent, SessionRecorder, SessionReplay};
+pub use crate::secret::Secret;
 pub use crate::ws::WebSocket;
+pub use crate::ws::ConnectionEvent;
+pub use crate::ws::ConnectionEventReceiver;
 pub use crate::ws::EventReceiver;
+pub use crate::ws::LoxoneFile;
+pub use crate::ws::LoxoneJwt;
+pub use crate::ws::ReconnectPolicy;
+pub use crate::ws::encode_event_tables;
+pub use crate::ws::encode_file_msg;
+pub use crate::ws::encode_keepalive_msg;
+pub use crate::ws::encode_out_of_service_msg;
+pub use crate::ws::encode_text_msg;
 
 pub mod errors {
+    pub use crate::cache::IconCacheError;
+    pub use crate::cache::StructureCacheError;
+    pub use crate::loxapp3::color::ParseColorError;
+    pub use crate::loxapp3::schedule::ScheduleError;
+    pub use crate::loxapp3::states::TextStateError;
+    pub use crate::loxapp3::statistics::StatisticsError;
+    pub use crate::record::ReplayError;
     pub use crate::ws::AuthenticationError;
     pub use crate::ws::JwtRequestError;
     pub use crate::ws::KeyExchangeError;
     pub use crate::ws::LoxAPP3RequestError;
+    pub use crate::ws::OutOfServiceError;
+    pub use crate::ws::ParseMessageError;
     pub use crate::ws::RequestError;
+    pub use crate::ws::StatisticsRequestError;
     pub use crate::ws::X509CertError;
 }
\ No newline at end of file
@@ -0,0 +1,299 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Color as used by color picker controls, encoded as `hsv(hue,saturation,value)` or `temp(brightness,kelvin)`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum LoxoneColor {
+    /// Hue in degrees (0-360), saturation and value in percent (0-100).
+    Hsv { hue: f64, saturation: f64, value: f64 },
+    /// Brightness in percent (0-100) and color temperature in Kelvin (2700-6500).
+    Temp { brightness: f64, temperature: f64 },
+}
+
+#[derive(Error, Debug, PartialEq)]
+#[error("invalid color {0:?}")]
+pub struct ParseColorError(pub String);
+
+impl LoxoneColor {
+    /// Creates a color from 8-bit sRGB components.
+    pub fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        let (r, g, b) = (f64::from(red) / 255.0, f64::from(green) / 255.0, f64::from(blue) / 255.0);
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+        Self::Hsv { hue: hue.round() % 360.0, saturation: (saturation * 100.0).round(), value: (max * 100.0).round() }
+    }
+
+    /// Creates a color from CIE 1931 xy chromaticity coordinates and brightness in percent (0-100).
+    pub fn from_xy(x: f64, y: f64, brightness: f64) -> Self {
+        if y <= 0.0 {
+            return Self::Hsv { hue: 0.0, saturation: 0.0, value: 0.0 };
+        }
+        let (cx, cz) = (x / y, (1.0 - x - y) / y);
+        let r = 3.2406 * cx - 1.5372 - 0.4986 * cz;
+        let g = -0.9689 * cx + 1.8758 + 0.0415 * cz;
+        let b = 0.0557 * cx - 0.2040 + 1.0570 * cz;
+        // Normalize to the brightest component, brightness is applied on the HSV value.
+        let max = r.max(g).max(b).max(f64::EPSILON);
+        let (r, g, b) = (gamma_encode(r / max), gamma_encode(g / max), gamma_encode(b / max));
+        match Self::from_rgb(to_u8(r), to_u8(g), to_u8(b)) {
+            Self::Hsv { hue, saturation, .. } => Self::Hsv { hue, saturation, value: brightness.clamp(0.0, 100.0).round() },
+            color => color,
+        }
+    }
+
+    /// Returns the 8-bit sRGB components of the color.
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let (r, g, b) = match *self {
+            Self::Hsv { hue, saturation, value } => hsv_to_rgb(hue, saturation / 100.0, value / 100.0),
+            Self::Temp { brightness, temperature } => {
+                let (r, g, b) = kelvin_to_rgb(temperature);
+                let brightness = brightness.clamp(0.0, 100.0) / 100.0;
+                (r * brightness, g * brightness, b * brightness)
+            },
+        };
+        (to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Returns the CIE 1931 xy chromaticity coordinates of the color.
+    ///
+    /// Brightness is not part of the chromaticity, see [`LoxoneColor::brightness`].
+    pub fn to_xy(&self) -> (f64, f64) {
+        match *self {
+            Self::Temp { temperature, .. } => kelvin_to_xy(temperature),
+            Self::Hsv { hue, saturation, .. } => {
+                let (r, g, b) = hsv_to_rgb(hue, saturation / 100.0, 1.0);
+                let (r, g, b) = (gamma_decode(r), gamma_decode(g), gamma_decode(b));
+                let x = 0.4124 * r + 0.3576 * g + 0.1805 * b;
+                let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+                let z = 0.0193 * r + 0.1192 * g + 0.9505 * b;
+                match x + y + z {
+                    sum if sum > 0.0 => (x / sum, y / sum),
+                    _ => kelvin_to_xy(6500.0),
+                }
+            },
+        }
+    }
+
+    /// Returns the brightness of the color in percent (0-100).
+    pub fn brightness(&self) -> f64 {
+        match *self {
+            Self::Hsv { value, .. } => value,
+            Self::Temp { brightness, .. } => brightness,
+        }
+    }
+}
+
+impl FromStr for LoxoneColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseColorError(s.to_owned());
+        let (name, args) = s.trim().trim_end_matches(')').split_once('(').ok_or_else(err)?;
+        let args = args.split(',').map(|arg| arg.trim().parse::<f64>()).collect::<Result<Vec<f64>, _>>().map_err(|_| err())?;
+        match (name.trim(), args.as_slice()) {
+            ("hsv", &[hue, saturation, value]) => Ok(Self::Hsv { hue, saturation, value }),
+            ("temp", &[brightness, temperature]) => Ok(Self::Temp { brightness, temperature }),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for LoxoneColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Hsv { hue, saturation, value } => write!(f, "hsv({},{},{})", hue, saturation, value),
+            Self::Temp { brightness, temperature } => write!(f, "temp({},{})", brightness, temperature),
+        }
+    }
+}
+
+impl TryFrom<String> for LoxoneColor {
+    type Error = ParseColorError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<LoxoneColor> for String {
+    fn from(color: LoxoneColor) -> Self {
+        color.to_string()
+    }
+}
+
+fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> (f64, f64, f64) {
+    let chroma = value * saturation;
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    (r + m, g + m, b + m)
+}
+
+/// Approximates the sRGB color of a black body radiator (Tanner Helland).
+fn kelvin_to_rgb(temperature: f64) -> (f64, f64, f64) {
+    let t = temperature.clamp(1000.0, 40000.0) / 100.0;
+    let r = if t <= 66.0 { 255.0 } else { 329.698727446 * (t - 60.0).powf(-0.1332047592) };
+    let g = if t <= 66.0 { 99.4708025861 * t.ln() - 161.1195681661 } else { 288.1221695283 * (t - 60.0).powf(-0.0755148492) };
+    let b = if t >= 66.0 { 255.0 } else if t <= 19.0 { 0.0 } else { 138.5177312231 * (t - 10.0).ln() - 305.0447927307 };
+    (r.clamp(0.0, 255.0) / 255.0, g.clamp(0.0, 255.0) / 255.0, b.clamp(0.0, 255.0) / 255.0)
+}
+
+/// Approximates the chromaticity of a black body radiator on the Planckian locus (Kim et al.).
+fn kelvin_to_xy(temperature: f64) -> (f64, f64) {
+    let t = temperature.clamp(1667.0, 25000.0);
+    let x = if t <= 4000.0 {
+        -0.2661239e9 / t.powi(3) - 0.2343589e6 / t.powi(2) + 0.8776956e3 / t + 0.179910
+    } else {
+        -3.0258469e9 / t.powi(3) + 2.1070379e6 / t.powi(2) + 0.2226347e3 / t + 0.240390
+    };
+    let y = if t <= 2222.0 {
+        -1.1063814 * x.powi(3) - 1.34811020 * x.powi(2) + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x.powi(3) - 1.37418593 * x.powi(2) + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x.powi(3) - 5.87338670 * x.powi(2) + 3.75112997 * x - 0.37001483
+    };
+    (x, y)
+}
+
+fn gamma_decode(c: f64) -> f64 {
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+fn gamma_encode(c: f64) -> f64 {
+    let c = c.max(0.0);
+    if c <= 0.0031308 { 12.92 * c } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
+}
+
+fn to_u8(c: f64) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hsv(hue: f64, saturation: f64, value: f64) -> LoxoneColor {
+        LoxoneColor::Hsv { hue, saturation, value }
+    }
+
+    fn assert_xy((x, y): (f64, f64), expected: (f64, f64)) {
+        assert!((x - expected.0).abs() < 1e-3 && (y - expected.1).abs() < 1e-3, "({}, {}) != {:?}", x, y, expected);
+    }
+
+    #[test]
+    fn converts_rgb_primaries() {
+        let primaries = [
+            ((255, 0, 0), hsv(0.0, 100.0, 100.0)),
+            ((0, 255, 0), hsv(120.0, 100.0, 100.0)),
+            ((0, 0, 255), hsv(240.0, 100.0, 100.0)),
+            ((255, 255, 255), hsv(0.0, 0.0, 100.0)),
+            ((0, 0, 0), hsv(0.0, 0.0, 0.0)),
+        ];
+        for &((r, g, b), color) in &primaries {
+            assert_eq!(LoxoneColor::from_rgb(r, g, b), color);
+            assert_eq!(color.to_rgb(), (r, g, b));
+        }
+        assert_eq!(hsv(240.0, 100.0, 50.0).to_rgb(), (0, 0, 128));
+        assert_eq!(hsv(360.0, 100.0, 100.0).to_rgb(), (255, 0, 0));
+    }
+
+    #[test]
+    fn round_trips_rgb_through_hsv() {
+        for &(r, g, b) in &[(255u8, 128u8, 0u8), (12, 200, 180), (90, 30, 160), (200, 200, 40), (17, 17, 17)] {
+            let (r2, g2, b2) = LoxoneColor::from_rgb(r, g, b).to_rgb();
+            for (c, c2) in [(r, r2), (g, g2), (b, b2)].iter() {
+                assert!((i16::from(*c) - i16::from(*c2)).abs() <= 3, "{:?} != {:?}", (r, g, b), (r2, g2, b2));
+            }
+        }
+    }
+
+    #[test]
+    fn converts_srgb_primaries_to_xy() {
+        assert_xy(hsv(0.0, 100.0, 100.0).to_xy(), (0.64, 0.33));
+        assert_xy(hsv(120.0, 100.0, 100.0).to_xy(), (0.30, 0.60));
+        assert_xy(hsv(240.0, 100.0, 100.0).to_xy(), (0.15, 0.06));
+        assert_xy(hsv(0.0, 0.0, 100.0).to_xy(), (0.3127, 0.3290));
+        // Chromaticity doesn't depend on the brightness.
+        assert_xy(hsv(0.0, 100.0, 10.0).to_xy(), (0.64, 0.33));
+    }
+
+    #[test]
+    fn converts_xy_to_hsv() {
+        assert_eq!(LoxoneColor::from_xy(0.64, 0.33, 100.0), hsv(0.0, 100.0, 100.0));
+        assert_eq!(LoxoneColor::from_xy(0.30, 0.60, 40.0), hsv(120.0, 100.0, 40.0));
+        assert_eq!(LoxoneColor::from_xy(0.15, 0.06, 150.0), hsv(240.0, 100.0, 100.0));
+        assert_eq!(LoxoneColor::from_xy(0.3127, 0.3290, 50.0), hsv(0.0, 0.0, 50.0));
+        assert_eq!(LoxoneColor::from_xy(0.3, 0.0, 50.0), hsv(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn round_trips_hsv_through_xy() {
+        for &(hue, saturation) in &[(30.0, 80.0), (75.0, 60.0), (200.0, 50.0), (300.0, 90.0)] {
+            let (x, y) = hsv(hue, saturation, 70.0).to_xy();
+            match LoxoneColor::from_xy(x, y, 70.0) {
+                LoxoneColor::Hsv { hue: hue2, saturation: saturation2, value } => {
+                    assert!((hue - hue2).abs() <= 2.0 && (saturation - saturation2).abs() <= 2.0, "hsv({},{}) != hsv({},{})", hue, saturation, hue2, saturation2);
+                    assert_eq!(value, 70.0);
+                },
+                color => panic!("unexpected {:?}", color),
+            }
+        }
+    }
+
+    #[test]
+    fn converts_color_temperatures() {
+        let warm = LoxoneColor::Temp { brightness: 50.0, temperature: 2700.0 };
+        let daylight = LoxoneColor::Temp { brightness: 100.0, temperature: 6500.0 };
+        assert_eq!(warm.to_rgb(), (128, 83, 44));
+        assert_eq!(daylight.to_rgb(), (255, 254, 250));
+        assert_xy(warm.to_xy(), (0.4593, 0.4107));
+        assert_xy(daylight.to_xy(), (0.3135, 0.3237));
+        // Temperatures outside the Planckian locus approximation are clamped.
+        assert_eq!(LoxoneColor::Temp { brightness: 100.0, temperature: 1000.0 }.to_xy(), kelvin_to_xy(1667.0));
+        assert_eq!((warm.brightness(), daylight.brightness()), (50.0, 100.0));
+    }
+
+    #[test]
+    fn parses_colors() {
+        assert_eq!("hsv(120,50,75)".parse(), Ok(hsv(120.0, 50.0, 75.0)));
+        assert_eq!(" hsv( 12.5 , 0 , 100 ) ".parse(), Ok(hsv(12.5, 0.0, 100.0)));
+        assert_eq!("temp(80,2700)".parse(), Ok(LoxoneColor::Temp { brightness: 80.0, temperature: 2700.0 }));
+        for s in &["hsv(120,50,75)", "hsv(12.5,0,100)", "temp(80,2700)"] {
+            assert_eq!(s.parse::<LoxoneColor>().unwrap().to_string(), *s);
+        }
+        assert_eq!(serde_json::from_str::<LoxoneColor>("\"temp(80,2700)\"").unwrap(), LoxoneColor::Temp { brightness: 80.0, temperature: 2700.0 });
+        assert_eq!(serde_json::to_string(&hsv(120.0, 50.0, 75.0)).unwrap(), "\"hsv(120,50,75)\"");
+    }
+
+    #[test]
+    fn rejects_malformed_colors() {
+        for s in &["", "hsv", "hsv()", "hsv(1,2)", "hsv(1,2,3,4)", "hsv(a,b,c)", "temp(80)", "temp(80,2700,1)", "rgb(1,2,3)", "(1,2,3)"] {
+            assert_eq!(s.parse::<LoxoneColor>(), Err(ParseColorError((*s).to_owned())));
+        }
+        assert!(serde_json::from_str::<LoxoneColor>("\"rgb(1,2,3)\"").is_err());
+    }
+}
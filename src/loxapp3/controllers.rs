@@ -1,36 +1,394 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::loxapp3::{LoxoneUUID, LoxoneMutation, LoxoneSubControl};
+use crate::loxapp3::{is_default, unmodeled_states, LoxoneStates, LoxoneUUID, LoxoneMutation, LoxoneSubControl};
+use crate::loxapp3::color::LoxoneColor;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AalEmergency {
+    pub states: AalEmergencyStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AalEmergencyStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_end_time: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_active: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AalSmartAlarm {
+    pub states: AalSmartAlarmStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AalSmartAlarmStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alarm_level: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alarm_cause: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_locked: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_leave_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_end_time: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Alarm {
+    pub details: AlarmDetails,
+    pub states: AlarmStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlarmDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub alert: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub presence_connected: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlarmStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub armed: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_level: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_level_delay: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_level_delay_total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub armed_delay: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub armed_delay_total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensors: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled_move: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlarmChain {
+    pub states: AlarmChainStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlarmChainStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alarm_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_alarm_text: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlarmClock {
+    pub details: AlarmClockDetails,
+    pub states: AlarmClockStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlarmClockDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_night_light: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub snooze_duration_connected: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub bright_inactive_connected: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub bright_active_connected: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub wake_alarm_sound_connected: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub wake_alarm_volume_connected: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub wake_alarm_sloping_connected: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlarmClockStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alarm_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_needed: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry_list: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_entry: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_entry: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_entry_mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ringing_time: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ring_duration: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prepare_duration: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snooze_time: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snooze_duration: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_entry_time: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_state: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AudioZone {
+    pub details: AudioZoneDetails,
+    pub states: AudioZoneStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioZoneDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub playerid: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_type: Option<u8>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioZoneStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_state: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_state: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_state: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_volume: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_step: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shuffle: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_list: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub song_name: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub album: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artist: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CarCharger {
+    pub details: CarChargerDetails,
+    pub states: CarChargerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarChargerDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charger_type: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy_format: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarChargerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connected: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_finished: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy_session: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_limit: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_limit: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_limit: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge_duration: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profiles: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_profile: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CentralAlarm {
+    pub details: CentralControllerDetails,
+    pub states: CentralAlarmStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CentralAlarmStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub armed: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CentralAudioZone {
+    pub details: CentralControllerDetails,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CentralGate {
+    pub details: CentralControllerDetails,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CentralJalousie {
+    pub details: CentralControllerDetails,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CentralControllerDetails {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub controls: Vec<CentralControllerControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CentralControllerControl {
+    pub uuid: LoxoneUUID,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u8>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CentralLightController {
-    pub details: CentralLightControllerDetails
+    pub details: CentralLightControllerDetails,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CentralLightControllerDetails {
     pub controls: Vec<CentralLightControllerControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CentralLightControllerControl {
     pub uuid: LoxoneUUID,
-    pub id: u8
+    pub id: u8,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
  pub struct ClimateController {
     pub details: ClimateControllerDetails,
     pub states: ClimateControllerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ClimateControllerDetails {
     pub capabilities: u8,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClimateControllerStates {
     pub controls: LoxoneUUID,
@@ -47,126 +405,557 @@ pub struct ClimateControllerStates {
     pub service_mode: LoxoneUUID,
     pub next_maintenance: LoxoneUUID,
     pub ventilation: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ColorPicker {
     pub details: ColorPickerDetails,
     pub states: ColorPickerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ColorPickerDetails {
     pub picker_type: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ColorPickerStates {
     pub color: LoxoneUUID,
     pub favorites: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ColorPickerV2 {
     pub states: ColorPickerV2States,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ColorPickerV2States {
     pub color: LoxoneUUID,
     pub sequence: LoxoneUUID,
     pub sequence_color_idx: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Daytimer {
+    pub details: DaytimerDetails,
+    pub states: DaytimerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DaytimerDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub analog: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<DaytimerText>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DaytimerText {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub on: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub off: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaytimerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoxoneUUID>,
+    pub r#override: LoxoneUUID,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries_and_default_value: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode_list: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Dimmer {
     pub states: DimmerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct DimmerStates {
     pub position: LoxoneUUID,
     pub min: LoxoneUUID,
     pub max: LoxoneUUID,
     pub step: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FanController {
+    pub details: FanControllerDetails,
+    pub states: FanControllerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FanControllerDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FanControllerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer_end: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Fronius {
+    pub details: FroniusDetails,
+    pub states: FroniusStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FroniusDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub show_battery: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub show_grid: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FroniusStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prod_curr: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prod_curr_day: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prod_curr_month: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prod_curr_year: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prod_total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cons_curr: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cons_curr_day: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grid_curr: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery_curr: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_of_charge: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earnings_day: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earnings_month: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earnings_year: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earnings_total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_delivery: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_consumption: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub co2_factor: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub online: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Gate {
+    pub details: GateDetails,
+    pub states: GateStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GateDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub animation: u8,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub is_gate_assigned: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GateStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_open: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_close: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Heatmixer {
+    pub states: HeatmixerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmixerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_actual: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_target: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mixer_position: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Hourcounter {
+    pub states: HourcounterStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HourcounterStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_activation: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overdue: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance_interval: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overdue_since: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct InfoOnlyAnalog {
     pub details: InfoOnlyAnalogDetails,
-    pub states: InfoOnlyStates
+    pub states: InfoOnlyStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct InfoOnlyDigital {
-    pub details: InfoOnlyAnalogDetails,
-    pub states: InfoOnlyStates
+    pub details: InfoOnlyDigitalDetails,
+    pub states: InfoOnlyDigitalStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct InfoOnlyAnalogDetails {
     pub format: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct InfoOnlyDigitalDetails {
-    pub text: u8,
-    pub image: LoxoneUUID,
-    pub color: u8
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<InfoOnlyDigitalText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<InfoOnlyDigitalImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<InfoOnlyDigitalColor>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InfoOnlyDigitalText {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub on: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub off: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InfoOnlyDigitalImage {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub on: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub off: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InfoOnlyDigitalColor {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub on: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub off: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct InfoOnlyStates {
     pub value: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InfoOnlyDigitalStates {
+    pub active: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Intercom {
+    pub details: IntercomDetails,
+    pub states: IntercomStates,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntercomDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_info: Option<IntercomVideoInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_info: Option<IntercomAudioInfo>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntercomVideoInfo {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub stream_url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub alert_image: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pass: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntercomAudioInfo {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub server_host: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pass: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntercomStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bell: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_bell_events: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct IRCV2Daytimer {
     pub details: IRCV2DaytimerDetails,
     pub states: IRCV2DaytimerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct IRCV2DaytimerDetails {
     pub format: String,
-    pub analog: bool
+    pub analog: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IRCV2DaytimerStates {
     pub entries_and_default_value: LoxoneUUID,
     pub mode: LoxoneUUID,
     pub mode_list: LoxoneUUID,
     pub value: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IRoomController {
+    pub details: IRoomControllerDetails,
+    pub states: IRoomControllerStates,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IRoomControllerDetails {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub temperatures: Vec<IRoomControllerTemperature>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub format: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timer_modes: Vec<IRoomControllerV2TimerMode>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IRoomControllerTemperature {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub is_absolute: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IRoomControllerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_actual: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_target: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curr_heat_temp_ix: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curr_cool_temp_ix: Option<LoxoneUUID>,
+    pub r#override: LoxoneUUID,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manual_mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperatures: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_window: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IRoomControllerV2 {
     pub details: IRoomControllerV2Details,
     pub states: IRoomControllerV2States,
     pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IRoomControllerV2Details {
     pub format: String,
     pub timer_modes: Vec<IRoomControllerV2TimerMode>,
     pub connected_inputs: u32,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct IRoomControllerV2TimerMode {
     pub id: u8,
     pub name: String,
-    pub description: String
+    pub description: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IRoomControllerV2States {
     pub active_mode: LoxoneUUID,
@@ -184,93 +973,481 @@ pub struct IRoomControllerV2States {
     pub heat_protect_temperature: LoxoneUUID,
     pub comfort_temperature_offset: LoxoneUUID,
     pub open_window: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Jalousie {
+    pub details: JalousieDetails,
+    pub states: JalousieStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JalousieDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub animation: u8,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub is_automatic: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JalousieStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub up: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub down: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shade_position: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_allowed: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info_text: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_position: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_position_lamelle: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NfcCodeTouchDetails {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub access_output: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub place: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     pub two_factor_auth: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct NfcCodeTouch {
     pub details: NfcCodeTouchDetails,
     pub states: NfcCodeTouchStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NfcCodeTouchStates {
     pub history_date: LoxoneUUID,
     pub code_date: LoxoneUUID,
     pub device_state: LoxoneUUID,
     pub nfc_learn_result: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LightController {
+    pub details: LightControllerDetails,
+    pub states: LightControllerStates,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LightControllerDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub movement_scene: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master_value: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master_color: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LightControllerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_scene: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scene_list: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LightControllerV2 {
     pub details: LightControllerV2Details,
     pub states: LightControllerV2States,
     pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LightControllerV2Details {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub master_value: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub master_color: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LightControllerV2States {
     pub active_moods: LoxoneUUID,
     pub mood_list: LoxoneUUID,
     pub favorite_moods: LoxoneUUID,
     pub additional_moods: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LightsceneRGB {
+    pub states: LightsceneRGBStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LightsceneRGBStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_scene: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scene_list: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MailBox {
+    pub states: MailBoxStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MailBoxStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notifications_disabled_input: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packet_received: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mail_received: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_end_time: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Meter {
+    pub details: MeterDetails,
+    pub states: MeterStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeterDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub actual_format: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub total_format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_format_neg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_format: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeterStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_neg: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolController {
+    pub details: PoolControllerDetails,
+    pub states: PoolControllerStates,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolControllerDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swimming_machine_type: Option<u8>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_eco_mode: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_valve: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_heating: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_cooling: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolControllerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_op_mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_temp_mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_actual: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_target: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pump: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backwash_time: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valve_pos: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Pushbutton {
+    pub states: PushbuttonStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PushbuttonStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Radio {
+    pub details: RadioDetails,
+    pub states: RadioStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RadioDetails {
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub outputs: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub all_off: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RadioStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_output: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Remote {
+    pub details: RemoteDetails,
+    pub states: RemoteStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteDetails {
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub mode_list: HashMap<String, RemoteMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorite_pad: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RemoteMode {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Sauna {
+    pub details: SaunaDetails,
+    pub states: SaunaStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaunaDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_vaporizer: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_door_sensor: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaunaStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_actual: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_bench: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_target: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fan: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drying: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub door_closed: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gong: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub less_water: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub humidity_actual: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub humidity_target: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaporator_mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer_total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_of_order: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Slider {
     pub details: SliderDetails,
-    pub states: SliderStates
+    pub states: SliderStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct SliderDetails {
     pub format: String,
     pub min: f32,
     pub max: f32,
     pub step: f32,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct SliderStates {
     pub value: LoxoneUUID,
     pub error: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SmokeWaterAlarm {
     pub details: SmokeWaterAlarmDetails,
     pub states: SmokeWaterAlarmStates,
     pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SmokeWaterAlarmDetails {
     pub has_acoustic_alarm: bool,
     pub available_alarms: u8,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SmokeWaterAlarmStates {
     pub next_level: LoxoneUUID,
@@ -284,20 +1461,396 @@ pub struct SmokeWaterAlarmStates {
     pub start_time: LoxoneUUID,
     pub time_service_mode: LoxoneUUID,
     pub are_alarm_signals_off: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SolarPumpController {
+    pub details: SolarPumpControllerDetails,
+    pub states: SolarPumpControllerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolarPumpControllerDetails {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub buffers: Vec<SolarPumpControllerBuffer>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolarPumpControllerBuffer {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolarPumpControllerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer_state: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collector_temp: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heat_buffer: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prio_buffer: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SteakThermo {
+    pub details: SteakThermoDetails,
+    pub states: SteakThermoStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SteakThermoDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yellow_sensor_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub green_sensor_name: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SteakThermoStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_actual: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_target: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alarm_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer_info: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_state: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Switch {
-    pub states: SwitchStates
+    pub states: SwitchStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct SwitchStates {
     pub active: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemScheme {
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub states: HashMap<String, LoxoneUUID>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TextState {
+    pub states: TextStateStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextStateStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_and_icon: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TextInput {
+    pub states: TextInputStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TextInputStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TimedSwitch {
+    pub states: TimedSwitchStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimedSwitchStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivation_delay_total: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivation_delay: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Tracker {
+    pub details: TrackerDetails,
+    pub states: TrackerStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub max_entries: u32,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TrackerStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpDownLeftRight {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<SliderDetails>,
+    pub states: UpDownLeftRightStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpDownLeftRightStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValueSelector {
+    pub details: ValueSelectorDetails,
+    pub states: ValueSelectorStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueSelectorDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub increase_only: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub format: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValueSelectorStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ventilation {
+    pub details: VentilationDetails,
+    pub states: VentilationStates,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sub_controls: HashMap<LoxoneUUID, LoxoneSubControl>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VentilationDetails {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_presence: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_indoor_humidity: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_air_quality: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_outdoor_temperature: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_indoor_temperature: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VentilationStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer_until: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub air_quality_indoor: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub humidity_indoor: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature_indoor: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature_outdoor: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frost_protect_active: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heat_exchanger_bypass: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Webpage {
+    pub details: WebpageDetails,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebpageDetails {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_hd: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_icon: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Window {
+    pub states: WindowStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked_reason: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_position: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WindowMonitor {
+    pub details: WindowMonitorDetails,
+    pub states: WindowMonitorStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WindowMonitorDetails {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub windows: Vec<WindowMonitorWindow>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowMonitorWindow {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install_place: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub room: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowMonitorStates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_states: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_open: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_closed: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_tilted: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_offline: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_locked: Option<LoxoneUUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_unlocked: Option<LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ColorPicker {
+    pub fn color(color: LoxoneColor) -> LoxoneMutation { color.to_string() }
 }
 
 impl ColorPickerV2 {
-    pub fn set_sequence(duration: u16, seq: &[LoxoneMutation], start_idx: i8) -> LoxoneMutation { format!("setSequence/{}/{}/{}", duration, seq.join("/"), start_idx) }
+    pub fn color(color: LoxoneColor) -> LoxoneMutation { color.to_string() }
+    pub fn set_sequence(duration: u16, seq: &[LoxoneColor], start_idx: i8) -> LoxoneMutation { format!("setSequence/{}/{}/{}", duration, seq.iter().map(LoxoneColor::to_string).collect::<Vec<_>>().join("/"), start_idx) }
     pub fn set_brightness(brightness: u8) -> LoxoneMutation { format!("setBrightness/{}", brightness) }
     pub fn hsv(hue: u16, saturation: u16, brightness: u8) -> LoxoneMutation { format!("hsv({},{},{})", hue, saturation, brightness) }
     pub fn temp(brightness: u8, temperature: u16) -> LoxoneMutation { format!("temp({},{})", brightness, temperature) }
@@ -316,4 +1869,425 @@ impl LightControllerV2 {
     pub fn remove(mood_id: u8) -> LoxoneMutation { format!("delete/{}", mood_id) }
     pub fn remove_from_favorite_mood(mood_id: u8) -> LoxoneMutation { format!("removeFromFavoriteMood/{}", mood_id) }
     pub fn remove_mood(mood_id: u8) -> LoxoneMutation { format!("removeMood/{}", mood_id) }
-}
\ No newline at end of file
+}
+
+/// State field of a controller, which may be missing for some configurations.
+trait StateUUID {
+    fn state_uuid(&self) -> Option<&LoxoneUUID>;
+}
+
+impl StateUUID for LoxoneUUID {
+    fn state_uuid(&self) -> Option<&LoxoneUUID> {
+        Some(self)
+    }
+}
+
+impl StateUUID for Option<LoxoneUUID> {
+    fn state_uuid(&self) -> Option<&LoxoneUUID> {
+        self.as_ref()
+    }
+}
+
+/// Implements [`LoxoneStates`] for states structs, mapping each field to its structure file state name.
+macro_rules! impl_states {
+    ($($states:ty { $($field:ident: $name:literal,)* })*) => {$(
+        impl LoxoneStates for $states {
+            fn state_uuids(&self) -> Vec<(&str, &LoxoneUUID)> {
+                let modeled = [$(($name, self.$field.state_uuid()),)*];
+                modeled.iter()
+                    .filter_map(|(name, uuid)| uuid.map(|uuid| (*name, uuid)))
+                    .chain(unmodeled_states(&self.other))
+                    .collect()
+            }
+        }
+    )*};
+}
+
+impl_states! {
+    AalEmergencyStates {
+        status: "status",
+        disable_end_time: "disableEndTime",
+        reset_active: "resetActive",
+    }
+    AalSmartAlarmStates {
+        alarm_level: "alarmLevel",
+        alarm_cause: "alarmCause",
+        is_locked: "isLocked",
+        is_leave_active: "isLeaveActive",
+        disable_end_time: "disableEndTime",
+    }
+    AlarmStates {
+        armed: "armed",
+        next_level: "nextLevel",
+        next_level_delay: "nextLevelDelay",
+        next_level_delay_total: "nextLevelDelayTotal",
+        level: "level",
+        start_time: "startTime",
+        armed_delay: "armedDelay",
+        armed_delay_total: "armedDelayTotal",
+        sensors: "sensors",
+        disabled_move: "disabledMove",
+    }
+    AlarmChainStates {
+        alarm_active: "alarmActive",
+        level: "level",
+        active_alarm_text: "activeAlarmText",
+    }
+    AlarmClockStates {
+        is_enabled: "isEnabled",
+        is_alarm_active: "isAlarmActive",
+        confirmation_needed: "confirmationNeeded",
+        entry_list: "entryList",
+        current_entry: "currentEntry",
+        next_entry: "nextEntry",
+        next_entry_mode: "nextEntryMode",
+        ringing_time: "ringingTime",
+        ring_duration: "ringDuration",
+        prepare_duration: "prepareDuration",
+        snooze_time: "snoozeTime",
+        snooze_duration: "snoozeDuration",
+        next_entry_time: "nextEntryTime",
+        device_state: "deviceState",
+    }
+    AudioZoneStates {
+        server_state: "serverState",
+        play_state: "playState",
+        client_state: "clientState",
+        power: "power",
+        volume: "volume",
+        max_volume: "maxVolume",
+        volume_step: "volumeStep",
+        shuffle: "shuffle",
+        source_list: "sourceList",
+        repeat: "repeat",
+        song_name: "songName",
+        duration: "duration",
+        progress: "progress",
+        album: "album",
+        artist: "artist",
+        station: "station",
+        genre: "genre",
+        cover: "cover",
+        source: "source",
+    }
+    CarChargerStates {
+        status: "status",
+        charging: "charging",
+        connected: "connected",
+        charging_finished: "chargingFinished",
+        power: "power",
+        energy_session: "energySession",
+        limit_mode: "limitMode",
+        current_limit: "currentLimit",
+        min_limit: "minLimit",
+        max_limit: "maxLimit",
+        charge_duration: "chargeDuration",
+        profiles: "profiles",
+        current_profile: "currentProfile",
+    }
+    CentralAlarmStates {
+        armed: "armed",
+    }
+    ClimateControllerStates {
+        controls: "controls",
+        current_mode: "currentMode",
+        auto_mode: "autoMode",
+        current_automatic: "currentAutomatic",
+        temperature_boundary_info: "temperatureBoundaryInfo",
+        heating_temp_boundary: "heatingTempBoundary",
+        cooling_temp_boundary: "coolingTempBoundary",
+        actual_outdoor_temp: "actualOutdoorTemp",
+        average_outdoor_temp: "averageOutdoorTemp",
+        overwrite_reason: "overwriteReason",
+        info_text: "infoText",
+        service_mode: "serviceMode",
+        next_maintenance: "nextMaintenance",
+        ventilation: "ventilation",
+    }
+    ColorPickerStates {
+        color: "color",
+        favorites: "favorites",
+    }
+    ColorPickerV2States {
+        color: "color",
+        sequence: "sequence",
+        sequence_color_idx: "sequenceColorIdx",
+    }
+    DaytimerStates {
+        mode: "mode",
+        r#override: "override",
+        value: "value",
+        entries_and_default_value: "entriesAndDefaultValue",
+        reset_active: "resetActive",
+        mode_list: "modeList",
+    }
+    DimmerStates {
+        position: "position",
+        min: "min",
+        max: "max",
+        step: "step",
+    }
+    FanControllerStates {
+        speed: "speed",
+        mode: "mode",
+        timer_end: "timerEnd",
+    }
+    FroniusStates {
+        prod_curr: "prodCurr",
+        prod_curr_day: "prodCurrDay",
+        prod_curr_month: "prodCurrMonth",
+        prod_curr_year: "prodCurrYear",
+        prod_total: "prodTotal",
+        cons_curr: "consCurr",
+        cons_curr_day: "consCurrDay",
+        grid_curr: "gridCurr",
+        battery_curr: "batteryCurr",
+        state_of_charge: "stateOfCharge",
+        earnings_day: "earningsDay",
+        earnings_month: "earningsMonth",
+        earnings_year: "earningsYear",
+        earnings_total: "earningsTotal",
+        price_delivery: "priceDelivery",
+        price_consumption: "priceConsumption",
+        co2_factor: "co2Factor",
+        mode: "mode",
+        online: "online",
+    }
+    GateStates {
+        position: "position",
+        active: "active",
+        prevent_open: "preventOpen",
+        prevent_close: "preventClose",
+    }
+    HeatmixerStates {
+        temp_actual: "tempActual",
+        temp_target: "tempTarget",
+        mixer_position: "mixerPosition",
+    }
+    HourcounterStates {
+        total: "total",
+        remaining: "remaining",
+        last_activation: "lastActivation",
+        overdue: "overdue",
+        maintenance_interval: "maintenanceInterval",
+        active: "active",
+        overdue_since: "overdueSince",
+    }
+    InfoOnlyStates {
+        value: "value",
+    }
+    InfoOnlyDigitalStates {
+        active: "active",
+    }
+    IntercomStates {
+        bell: "bell",
+        last_bell_events: "lastBellEvents",
+        version: "version",
+    }
+    IRCV2DaytimerStates {
+        entries_and_default_value: "entriesAndDefaultValue",
+        mode: "mode",
+        mode_list: "modeList",
+        value: "value",
+    }
+    IRoomControllerStates {
+        temp_actual: "tempActual",
+        temp_target: "tempTarget",
+        mode: "mode",
+        service_mode: "serviceMode",
+        curr_heat_temp_ix: "currHeatTempIx",
+        curr_cool_temp_ix: "currCoolTempIx",
+        r#override: "override",
+        override_total: "overrideTotal",
+        manual_mode: "manualMode",
+        temperatures: "temperatures",
+        open_window: "openWindow",
+    }
+    IRoomControllerV2States {
+        active_mode: "activeMode",
+        operating_mode: "operatingMode",
+        override_entries: "overrideEntries",
+        prepare_state: "prepareState",
+        override_reason: "overrideReason",
+        temp_actual: "tempActual",
+        temp_target: "tempTarget",
+        comfort_temperature: "comfortTemperature",
+        comfort_tolerance: "comfortTolerance",
+        absent_min_offset: "absentMinOffset",
+        absent_max_offset: "absentMaxOffset",
+        frost_protect_temperature: "frostProtectTemperature",
+        heat_protect_temperature: "heatProtectTemperature",
+        comfort_temperature_offset: "comfortTemperatureOffset",
+        open_window: "openWindow",
+    }
+    JalousieStates {
+        up: "up",
+        down: "down",
+        position: "position",
+        shade_position: "shadePosition",
+        safety_active: "safetyActive",
+        auto_allowed: "autoAllowed",
+        auto_active: "autoActive",
+        locked: "locked",
+        info_text: "infoText",
+        target_position: "targetPosition",
+        target_position_lamelle: "targetPositionLamelle",
+    }
+    NfcCodeTouchStates {
+        history_date: "historyDate",
+        code_date: "codeDate",
+        device_state: "deviceState",
+        nfc_learn_result: "nfcLearnResult",
+    }
+    LightControllerStates {
+        active_scene: "activeScene",
+        scene_list: "sceneList",
+    }
+    LightControllerV2States {
+        active_moods: "activeMoods",
+        mood_list: "moodList",
+        favorite_moods: "favoriteMoods",
+        additional_moods: "additionalMoods",
+    }
+    LightsceneRGBStates {
+        active_scene: "activeScene",
+        scene_list: "sceneList",
+        color: "color",
+    }
+    MailBoxStates {
+        notifications_disabled_input: "notificationsDisabledInput",
+        packet_received: "packetReceived",
+        mail_received: "mailReceived",
+        disable_end_time: "disableEndTime",
+    }
+    MeterStates {
+        actual: "actual",
+        total: "total",
+        total_neg: "totalNeg",
+        storage: "storage",
+    }
+    PoolControllerStates {
+        current_op_mode: "currentOpMode",
+        current_temp_mode: "currentTempMode",
+        temp_actual: "tempActual",
+        temp_target: "tempTarget",
+        pump: "pump",
+        filter: "filter",
+        backwash_time: "backwashTime",
+        valve_pos: "valvePos",
+        error: "error",
+    }
+    PushbuttonStates {
+        active: "active",
+    }
+    RadioStates {
+        active_output: "activeOutput",
+    }
+    RemoteStates {
+        mode: "mode",
+        active: "active",
+        timeout: "timeout",
+    }
+    SaunaStates {
+        power: "power",
+        temp_actual: "tempActual",
+        temp_bench: "tempBench",
+        temp_target: "tempTarget",
+        fan: "fan",
+        drying: "drying",
+        door_closed: "doorClosed",
+        gong: "gong",
+        less_water: "lessWater",
+        humidity_actual: "humidityActual",
+        humidity_target: "humidityTarget",
+        evaporator_mode: "evaporatorMode",
+        timer: "timer",
+        timer_total: "timerTotal",
+        out_of_order: "outOfOrder",
+        mode: "mode",
+        error: "error",
+    }
+    SliderStates {
+        value: "value",
+        error: "error",
+    }
+    SmokeWaterAlarmStates {
+        next_level: "nextLevel",
+        next_level_delay: "nextLevelDelay",
+        next_level_delay_total: "nextLevelDelayTotal",
+        level: "level",
+        sensors: "sensors",
+        acoustic_alarm: "acousticAlarm",
+        test_alarm: "testAlarm",
+        alarm_cause: "alarmCause",
+        start_time: "startTime",
+        time_service_mode: "timeServiceMode",
+        are_alarm_signals_off: "areAlarmSignalsOff",
+    }
+    SolarPumpControllerStates {
+        buffer_state: "bufferState",
+        collector_temp: "collectorTemp",
+        heat_buffer: "heatBuffer",
+        prio_buffer: "prioBuffer",
+    }
+    SteakThermoStates {
+        is_active: "isActive",
+        temp_actual: "tempActual",
+        temp_target: "tempTarget",
+        alarm_active: "alarmActive",
+        timer_info: "timerInfo",
+        device_state: "deviceState",
+    }
+    SwitchStates {
+        active: "active",
+    }
+    TextStateStates {
+        text_and_icon: "textAndIcon",
+    }
+    TextInputStates {
+        text: "text",
+    }
+    TimedSwitchStates {
+        deactivation_delay_total: "deactivationDelayTotal",
+        deactivation_delay: "deactivationDelay",
+    }
+    TrackerStates {
+        entries: "entries",
+    }
+    UpDownLeftRightStates {
+        value: "value",
+    }
+    ValueSelectorStates {
+        value: "value",
+        min: "min",
+        max: "max",
+        step: "step",
+    }
+    VentilationStates {
+        mode: "mode",
+        speed: "speed",
+        timer_until: "timerUntil",
+        air_quality_indoor: "airQualityIndoor",
+        humidity_indoor: "humidityIndoor",
+        temperature_indoor: "temperatureIndoor",
+        temperature_outdoor: "temperatureOutdoor",
+        presence: "presence",
+        frost_protect_active: "frostProtectActive",
+        heat_exchanger_bypass: "heatExchangerBypass",
+    }
+    WindowStates {
+        position: "position",
+        direction: "direction",
+        locked_reason: "lockedReason",
+        target_position: "targetPosition",
+    }
+    WindowMonitorStates {
+        window_states: "windowStates",
+        num_open: "numOpen",
+        num_closed: "numClosed",
+        num_tilted: "numTilted",
+        num_offline: "numOffline",
+        num_locked: "numLocked",
+        num_unlocked: "numUnlocked",
+    }
+}
@@ -0,0 +1,312 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::loxapp3::{LoxoneApp3, LoxoneUUID};
+
+/// Differences between two versions of the structure file.
+#[derive(Debug, Default, Serialize)]
+pub struct LoxoneApp3Diff {
+    pub changes: Vec<LoxoneChange>,
+}
+
+/// Single difference between two versions of the structure file.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "camelCase")]
+pub enum LoxoneChange {
+    #[serde(rename_all = "camelCase")]
+    Added { kind: LoxoneItemKind, uuid: LoxoneUUID, name: String },
+    #[serde(rename_all = "camelCase")]
+    Removed { kind: LoxoneItemKind, uuid: LoxoneUUID, name: String },
+    #[serde(rename_all = "camelCase")]
+    Renamed { kind: LoxoneItemKind, uuid: LoxoneUUID, old_name: String, new_name: String },
+    #[serde(rename_all = "camelCase")]
+    Moved { uuid: LoxoneUUID, name: String, old_room: Option<LoxoneUUID>, new_room: Option<LoxoneUUID>, old_cat: Option<LoxoneUUID>, new_cat: Option<LoxoneUUID> },
+    #[serde(rename_all = "camelCase")]
+    TypeChanged { uuid: LoxoneUUID, name: String, old_type: String, new_type: String },
+    #[serde(rename_all = "camelCase")]
+    StateChanged { uuid: LoxoneUUID, name: String, state: String, old_uuid: Option<LoxoneUUID>, new_uuid: Option<LoxoneUUID> },
+}
+
+/// Kind of structure file item affected by a change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LoxoneItemKind {
+    Control,
+    Room,
+    Category,
+}
+
+impl LoxoneApp3Diff {
+    /// Computes the differences from `old` to `new`.
+    ///
+    /// Controls include sub-controls. Moves are only reported for top-level controls, as sub-controls follow their parent.
+    pub fn new(old: &LoxoneApp3, new: &LoxoneApp3) -> Self {
+        let mut changes = Vec::new();
+        diff_items(
+            LoxoneItemKind::Room,
+            old.rooms.iter().map(|(uuid, room)| (uuid, room.name.as_str())).collect(),
+            new.rooms.iter().map(|(uuid, room)| (uuid, room.name.as_str())).collect(),
+            &mut changes,
+        );
+        diff_items(
+            LoxoneItemKind::Category,
+            old.cats.iter().map(|(uuid, cat)| (uuid, cat.name.as_str())).collect(),
+            new.cats.iter().map(|(uuid, cat)| (uuid, cat.name.as_str())).collect(),
+            &mut changes,
+        );
+
+        let (old_index, new_index) = (old.index(), new.index());
+        let old_controls: BTreeMap<&LoxoneUUID, &str> = old_index.iter().map(|entry| (entry.uuid, entry.name)).collect();
+        let new_controls: BTreeMap<&LoxoneUUID, &str> = new_index.iter().map(|entry| (entry.uuid, entry.name)).collect();
+        diff_items(LoxoneItemKind::Control, old_controls.clone(), new_controls.clone(), &mut changes);
+
+        for uuid in old_controls.keys().filter(|uuid| new_controls.contains_key(*uuid)) {
+            let (old_entry, new_entry) = match (old_index.get(uuid), new_index.get(uuid)) {
+                (Some(old_entry), Some(new_entry)) => (old_entry, new_entry),
+                _ => continue,
+            };
+            if new_entry.parent.is_none() && (old_entry.room != new_entry.room || old_entry.cat != new_entry.cat) {
+                changes.push(LoxoneChange::Moved {
+                    uuid: new_entry.uuid.clone(),
+                    name: new_entry.name.to_owned(),
+                    old_room: old_entry.room.cloned(),
+                    new_room: new_entry.room.cloned(),
+                    old_cat: old_entry.cat.cloned(),
+                    new_cat: new_entry.cat.cloned(),
+                });
+            }
+            if old_entry.controller.type_name() != new_entry.controller.type_name() {
+                changes.push(LoxoneChange::TypeChanged {
+                    uuid: new_entry.uuid.clone(),
+                    name: new_entry.name.to_owned(),
+                    old_type: old_entry.controller.type_name().to_owned(),
+                    new_type: new_entry.controller.type_name().to_owned(),
+                });
+            }
+            let old_states = old_entry.controller.states();
+            let new_states = new_entry.controller.states();
+            let state_names: BTreeSet<&String> = old_states.keys().chain(new_states.keys()).collect();
+            for state in state_names {
+                if old_states.get(state) != new_states.get(state) {
+                    changes.push(LoxoneChange::StateChanged {
+                        uuid: new_entry.uuid.clone(),
+                        name: new_entry.name.to_owned(),
+                        state: state.clone(),
+                        old_uuid: old_states.get(state).cloned(),
+                        new_uuid: new_states.get(state).cloned(),
+                    });
+                }
+            }
+        }
+        Self { changes }
+    }
+
+    /// Returns `true` if both versions are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for LoxoneApp3Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LoxoneChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Added { kind, uuid, name } => write!(f, "+ {} \"{}\" ({})", kind, name, uuid),
+            Self::Removed { kind, uuid, name } => write!(f, "- {} \"{}\" ({})", kind, name, uuid),
+            Self::Renamed { kind, uuid, old_name, new_name } => write!(f, "~ {} \"{}\" renamed to \"{}\" ({})", kind, old_name, new_name, uuid),
+            Self::Moved { uuid, name, old_room, new_room, old_cat, new_cat } =>
+                write!(f, "~ control \"{}\" moved from room {} / category {} to room {} / category {} ({})", name, display_uuid(old_room), display_uuid(old_cat), display_uuid(new_room), display_uuid(new_cat), uuid),
+            Self::TypeChanged { uuid, name, old_type, new_type } => write!(f, "~ control \"{}\" changed type from {} to {} ({})", name, old_type, new_type, uuid),
+            Self::StateChanged { uuid, name, state, old_uuid, new_uuid } =>
+                write!(f, "~ control \"{}\" state {} changed from {} to {} ({})", name, state, display_uuid(old_uuid), display_uuid(new_uuid), uuid),
+        }
+    }
+}
+
+impl fmt::Display for LoxoneItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Control => f.write_str("control"),
+            Self::Room => f.write_str("room"),
+            Self::Category => f.write_str("category"),
+        }
+    }
+}
+
+impl LoxoneApp3 {
+    /// Returns the differences from this structure file to the `new` one.
+    pub fn diff(&self, new: &LoxoneApp3) -> LoxoneApp3Diff {
+        LoxoneApp3Diff::new(self, new)
+    }
+}
+
+fn diff_items(kind: LoxoneItemKind, old: BTreeMap<&LoxoneUUID, &str>, new: BTreeMap<&LoxoneUUID, &str>, changes: &mut Vec<LoxoneChange>) {
+    for (uuid, name) in &old {
+        match new.get(uuid) {
+            None => changes.push(LoxoneChange::Removed { kind, uuid: (*uuid).clone(), name: (*name).to_owned() }),
+            Some(new_name) if new_name != name => changes.push(LoxoneChange::Renamed { kind, uuid: (*uuid).clone(), old_name: (*name).to_owned(), new_name: (*new_name).to_owned() }),
+            Some(_) => (),
+        }
+    }
+    for (uuid, name) in new.iter().filter(|(uuid, _)| !old.contains_key(*uuid)) {
+        changes.push(LoxoneChange::Added { kind, uuid: (*uuid).clone(), name: (*name).to_owned() });
+    }
+}
+
+fn display_uuid(uuid: &Option<LoxoneUUID>) -> &str {
+    uuid.as_deref().unwrap_or("none")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const LOXAPP3: &str = include_str!("../../tests/fixtures/LoxAPP3.json");
+    const LIVING_ROOM: &str = "10001111-0a01-0c01-ffff504f94a00001";
+    const KITCHEN: &str = "30001111-0c01-0c01-ffff504f94a00001";
+    const LIGHTING: &str = "10002222-0a02-0c02-ffff504f94a00002";
+    const LIGHT_CONTROLLER: &str = "10044440-0a40-0c40-ffff504f94a00040";
+
+    fn loxapp3(json: serde_json::Value) -> LoxoneApp3 {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn uuid(uuid: &str) -> LoxoneUUID {
+        uuid.to_owned()
+    }
+
+    fn changed() -> serde_json::Value {
+        let mut json: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+        json["rooms"][KITCHEN] = json!({ "uuid": KITCHEN, "name": "Kitchen", "image": "", "defaultRating": 0, "isFavorite": false, "type": 0 });
+        json["cats"][LIGHTING]["name"] = json!("Lights");
+        let controls = json["controls"].as_object_mut().unwrap();
+        controls.remove("10014443-0a13-0c13-ffff504f94a00013");
+        controls.insert(String::from("30002222-0c02-0c02-ffff504f94a00002"), json!({
+            "name": "Hall Switch",
+            "type": "Switch",
+            "uuidAction": "30002222-0c02-0c02-ffff504f94a00002",
+            "room": KITCHEN,
+            "cat": LIGHTING,
+            "defaultRating": 0,
+            "isFavorite": false,
+            "isSecured": false,
+            "states": { "active": "30003333-0c03-0c03-ffff504f94a00003" },
+        }));
+        controls["10004444-0a04-0c04-ffff504f94a00004"]["name"] = json!("Kitchen Outlet");
+        controls["1000eeee-0a0e-0c0e-ffff504f94a0000e"]["room"] = json!(KITCHEN);
+        let sub_controls = &mut controls[LIGHT_CONTROLLER]["subControls"];
+        sub_controls["1004bbb7-0a47-0c47-ffff504f94a00047"]["type"] = json!("Pushbutton");
+        let dimmer_states = sub_controls["10049995-0a45-0c45-ffff504f94a00045"]["states"].as_object_mut().unwrap();
+        dimmer_states["position"] = json!("30004444-0c04-0c04-ffff504f94a00004");
+        dimmer_states.remove("step");
+        json
+    }
+
+    #[test]
+    fn finds_no_changes_between_equal_versions() {
+        let json: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+        let diff = loxapp3(json.clone()).diff(&loxapp3(json));
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn finds_changes() {
+        let old = loxapp3(serde_json::from_str(LOXAPP3).unwrap());
+        let diff = old.diff(&loxapp3(changed()));
+        assert_eq!(diff.changes, [
+            LoxoneChange::Added { kind: LoxoneItemKind::Room, uuid: uuid(KITCHEN), name: String::from("Kitchen") },
+            LoxoneChange::Renamed { kind: LoxoneItemKind::Category, uuid: uuid(LIGHTING), old_name: String::from("Lighting"), new_name: String::from("Lights") },
+            LoxoneChange::Renamed {
+                kind: LoxoneItemKind::Control,
+                uuid: uuid("10004444-0a04-0c04-ffff504f94a00004"),
+                old_name: String::from("Kitchen Socket"),
+                new_name: String::from("Kitchen Outlet"),
+            },
+            LoxoneChange::Removed { kind: LoxoneItemKind::Control, uuid: uuid("10014443-0a13-0c13-ffff504f94a00013"), name: String::from("Garage Door") },
+            LoxoneChange::Added { kind: LoxoneItemKind::Control, uuid: uuid("30002222-0c02-0c02-ffff504f94a00002"), name: String::from("Hall Switch") },
+            LoxoneChange::Moved {
+                uuid: uuid("1000eeee-0a0e-0c0e-ffff504f94a0000e"),
+                name: String::from("Living Room Blinds"),
+                old_room: Some(uuid(LIVING_ROOM)),
+                new_room: Some(uuid(KITCHEN)),
+                old_cat: Some(uuid(LIGHTING)),
+                new_cat: Some(uuid(LIGHTING)),
+            },
+            LoxoneChange::StateChanged {
+                uuid: uuid("10049995-0a45-0c45-ffff504f94a00045"),
+                name: String::from("Ceiling Light"),
+                state: String::from("position"),
+                old_uuid: Some(uuid("10045551-0a41-0c41-ffff504f94a00041")),
+                new_uuid: Some(uuid("30004444-0c04-0c04-ffff504f94a00004")),
+            },
+            LoxoneChange::StateChanged {
+                uuid: uuid("10049995-0a45-0c45-ffff504f94a00045"),
+                name: String::from("Ceiling Light"),
+                state: String::from("step"),
+                old_uuid: Some(uuid("10048884-0a44-0c44-ffff504f94a00044")),
+                new_uuid: None,
+            },
+            LoxoneChange::TypeChanged {
+                uuid: uuid("1004bbb7-0a47-0c47-ffff504f94a00047"),
+                name: String::from("Floor Lamp"),
+                old_type: String::from("Switch"),
+                new_type: String::from("Pushbutton"),
+            },
+        ]);
+    }
+
+    #[test]
+    fn doesnt_move_sub_controls() {
+        let mut json: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+        json["controls"][LIGHT_CONTROLLER]["subControls"]["1004bbb7-0a47-0c47-ffff504f94a00047"]["room"] = json!(KITCHEN);
+        let old = loxapp3(serde_json::from_str(LOXAPP3).unwrap());
+        assert!(old.diff(&loxapp3(json)).is_empty());
+    }
+
+    #[test]
+    fn formats_changes() {
+        let old = loxapp3(serde_json::from_str(LOXAPP3).unwrap());
+        let diff = old.diff(&loxapp3(changed()));
+        assert_eq!(serde_json::to_value(&diff.changes[2..6]).unwrap(), json!([
+            { "change": "renamed", "kind": "control", "uuid": "10004444-0a04-0c04-ffff504f94a00004", "oldName": "Kitchen Socket", "newName": "Kitchen Outlet" },
+            { "change": "removed", "kind": "control", "uuid": "10014443-0a13-0c13-ffff504f94a00013", "name": "Garage Door" },
+            { "change": "added", "kind": "control", "uuid": "30002222-0c02-0c02-ffff504f94a00002", "name": "Hall Switch" },
+            {
+                "change": "moved",
+                "uuid": "1000eeee-0a0e-0c0e-ffff504f94a0000e",
+                "name": "Living Room Blinds",
+                "oldRoom": LIVING_ROOM,
+                "newRoom": KITCHEN,
+                "oldCat": LIGHTING,
+                "newCat": LIGHTING,
+            },
+        ]));
+        assert_eq!(serde_json::to_value(&diff.changes[7..]).unwrap(), json!([
+            { "change": "stateChanged", "uuid": "10049995-0a45-0c45-ffff504f94a00045", "name": "Ceiling Light", "state": "step", "oldUuid": "10048884-0a44-0c44-ffff504f94a00044", "newUuid": null },
+            { "change": "typeChanged", "uuid": "1004bbb7-0a47-0c47-ffff504f94a00047", "name": "Floor Lamp", "oldType": "Switch", "newType": "Pushbutton" },
+        ]));
+        assert_eq!(diff.to_string().lines().collect::<Vec<_>>(), [
+            "+ room \"Kitchen\" (30001111-0c01-0c01-ffff504f94a00001)",
+            "~ category \"Lighting\" renamed to \"Lights\" (10002222-0a02-0c02-ffff504f94a00002)",
+            "~ control \"Kitchen Socket\" renamed to \"Kitchen Outlet\" (10004444-0a04-0c04-ffff504f94a00004)",
+            "- control \"Garage Door\" (10014443-0a13-0c13-ffff504f94a00013)",
+            "+ control \"Hall Switch\" (30002222-0c02-0c02-ffff504f94a00002)",
+            "~ control \"Living Room Blinds\" moved from room 10001111-0a01-0c01-ffff504f94a00001 / category 10002222-0a02-0c02-ffff504f94a00002 \
+             to room 30001111-0c01-0c01-ffff504f94a00001 / category 10002222-0a02-0c02-ffff504f94a00002 (1000eeee-0a0e-0c0e-ffff504f94a0000e)",
+            "~ control \"Ceiling Light\" state position changed from 10045551-0a41-0c41-ffff504f94a00041 to 30004444-0c04-0c04-ffff504f94a00004 (10049995-0a45-0c45-ffff504f94a00045)",
+            "~ control \"Ceiling Light\" state step changed from 10048884-0a44-0c44-ffff504f94a00044 to none (10049995-0a45-0c45-ffff504f94a00045)",
+            "~ control \"Floor Lamp\" changed type from Switch to Pushbutton (1004bbb7-0a47-0c47-ffff504f94a00047)",
+        ]);
+    }
+}
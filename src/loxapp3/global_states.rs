@@ -0,0 +1,124 @@
+use chrono::{DateTime, FixedOffset, NaiveTime};
+use std::collections::HashMap;
+
+use crate::epoch::LoxoneTimestamp;
+use crate::loxapp3::{LoxoneApp3, LoxoneGlobalStates, LoxoneState, LoxoneUUID};
+use crate::loxapp3::states::{decode_global_text_state, LoxoneNotification, LoxoneTextState, TextStateError};
+
+/// Typed view over the global states of the structure file, backed by a state cache.
+pub struct GlobalStatesView<'a> {
+    global_states: &'a LoxoneGlobalStates,
+    states: &'a HashMap<LoxoneUUID, LoxoneState>,
+}
+
+impl<'a> GlobalStatesView<'a> {
+    pub fn new(global_states: &'a LoxoneGlobalStates, states: &'a HashMap<LoxoneUUID, LoxoneState>) -> Self {
+        Self { global_states, states }
+    }
+
+    /// Returns today's sunrise in Miniserver local time.
+    pub fn sunrise(&self) -> Option<NaiveTime> {
+        self.time_of_day(&self.global_states.sunrise)
+    }
+
+    /// Returns today's sunset in Miniserver local time.
+    pub fn sunset(&self) -> Option<NaiveTime> {
+        self.time_of_day(&self.global_states.sunset)
+    }
+
+    /// Returns the Miniserver clock, given the Miniserver time zone offset.
+    pub fn miniserver_time(&self, offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+        self.timestamp(&self.global_states.miniserver_time).map(|timestamp| timestamp.with_offset(offset))
+    }
+
+    /// Returns whether the Miniserver is connected to the internet.
+    pub fn has_internet(&self) -> Option<bool> {
+        self.value(&self.global_states.has_internet).map(|value| value != 0.0)
+    }
+
+    /// Returns the pending notifications, empty if none were received yet.
+    pub fn notifications(&self) -> Result<Vec<LoxoneNotification>, TextStateError> {
+        match self.states.get(&self.global_states.notifications) {
+            Some(LoxoneState::Text(text, _)) if !text.is_empty() => match decode_global_text_state("notifications", text)? {
+                LoxoneTextState::Notifications(notifications) => Ok(notifications),
+                _ => Ok(Vec::new()),
+            },
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    fn value(&self, uuid: &str) -> Option<f64> {
+        match self.states.get(uuid)? {
+            LoxoneState::Value(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn timestamp(&self, uuid: &str) -> Option<LoxoneTimestamp> {
+        self.states.get(uuid)?.as_timestamp()
+    }
+
+    /// Converts a state holding minutes since midnight, `None` if it is out of range.
+    fn time_of_day(&self, uuid: &str) -> Option<NaiveTime> {
+        match self.value(uuid)? {
+            minutes if (0.0..1440.0).contains(&minutes) => NaiveTime::from_hms_opt(minutes as u32 / 60, minutes as u32 % 60, 0),
+            _ => None,
+        }
+    }
+}
+
+impl LoxoneApp3 {
+    /// Returns a typed view over the global states, backed by the given state cache.
+    pub fn global_states_view<'a>(&'a self, states: &'a HashMap<LoxoneUUID, LoxoneState>) -> GlobalStatesView<'a> {
+        GlobalStatesView::new(&self.global_states, states)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOXAPP3: &str = include_str!("../../tests/fixtures/LoxAPP3.json");
+    const SUNRISE: &str = "1004fffb-0a4b-0c4b-ffff504f94a0004b";
+    const SUNSET: &str = "1004eeea-0a4a-0c4a-ffff504f94a0004a";
+    const MINISERVER_TIME: &str = "1005443f-0a4f-0c4f-ffff504f94a0004f";
+    const HAS_INTERNET: &str = "10056661-0a51-0c51-ffff504f94a00051";
+
+    fn states(states: &[(&str, LoxoneState)]) -> HashMap<LoxoneUUID, LoxoneState> {
+        states.iter().map(|(uuid, state)| ((*uuid).to_owned(), state.clone())).collect()
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn converts_sunrise_and_sunset() {
+        let loxapp3: LoxoneApp3 = serde_json::from_str(LOXAPP3).unwrap();
+        let states = states(&[(SUNRISE, LoxoneState::Value(382.0)), (SUNSET, LoxoneState::Value(1259.9))]);
+        let view = loxapp3.global_states_view(&states);
+        assert_eq!((view.sunrise(), view.sunset()), (Some(time(6, 22)), Some(time(20, 59))));
+
+        for &(minutes, expected) in &[(0.0, Some(time(0, 0))), (1439.0, Some(time(23, 59))), (1440.0, None), (-1.0, None), (f64::NAN, None)] {
+            let states = self::states(&[(SUNRISE, LoxoneState::Value(minutes))]);
+            assert_eq!(loxapp3.global_states_view(&states).sunrise(), expected, "{} minutes", minutes);
+        }
+        let states = self::states(&[(SUNRISE, LoxoneState::Text(String::from("382"), String::new()))]);
+        assert_eq!(loxapp3.global_states_view(&states).sunrise(), None);
+        assert_eq!(loxapp3.global_states_view(&HashMap::new()).sunset(), None);
+    }
+
+    #[test]
+    fn converts_miniserver_time() {
+        let loxapp3: LoxoneApp3 = serde_json::from_str(LOXAPP3).unwrap();
+        // 2026-07-01 12:00:00 Miniserver local time.
+        let states = states(&[(MINISERVER_TIME, LoxoneState::Value(552_139_200.0)), (HAS_INTERNET, LoxoneState::Value(1.0))]);
+        let view = loxapp3.global_states_view(&states);
+        let time = view.miniserver_time(FixedOffset::east_opt(2 * 3600).unwrap()).unwrap();
+        assert_eq!(time.to_rfc3339(), "2026-07-01T12:00:00+02:00");
+        assert_eq!(time.timestamp(), 1_782_900_000);
+        assert_eq!(view.miniserver_time(FixedOffset::east_opt(0).unwrap()).unwrap().to_rfc3339(), "2026-07-01T12:00:00+00:00");
+        assert_eq!(view.has_internet(), Some(true));
+        assert_eq!(loxapp3.global_states_view(&HashMap::new()).miniserver_time(FixedOffset::east_opt(0).unwrap()), None);
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::loxapp3::{LoxoneApp3, LoxoneController, LoxoneState, LoxoneStates, LoxoneUUID};
+use crate::loxapp3::states::{decode_global_text_state, decode_message_center_text_state, decode_text_state, LoxoneTextState, TextStateError};
+
+/// Indexed view over the structure file.
+///
+/// Sub-controls are flattened into the index and inherit room and category from their parent control.
+pub struct LoxoneIndex<'a> {
+    entries: HashMap<&'a str, LoxoneIndexEntry<'a>>,
+    states: HashMap<LoxoneUUID, (&'a str, String)>,
+    global_states: HashMap<LoxoneUUID, String>,
+    message_center_states: HashSet<&'a str>,
+    by_room: HashMap<&'a str, Vec<&'a str>>,
+    by_cat: HashMap<&'a str, Vec<&'a str>>,
+    by_type: HashMap<&'a str, Vec<&'a str>>,
+}
+
+/// Control or sub-control in the index.
+#[derive(Debug, Clone, Copy)]
+pub struct LoxoneIndexEntry<'a> {
+    pub uuid: &'a LoxoneUUID,
+    pub name: &'a str,
+    pub controller: &'a LoxoneController,
+    pub parent: Option<&'a LoxoneUUID>,
+    pub room: Option<&'a LoxoneUUID>,
+    pub cat: Option<&'a LoxoneUUID>,
+    pub is_favorite: bool,
+}
+
+/// Control owning a state, as returned by [`LoxoneIndex::state_owner`].
+#[derive(Debug, Clone, Copy)]
+pub struct LoxoneStateOwner<'a, 'b> {
+    pub control: &'b LoxoneIndexEntry<'a>,
+    pub state_name: &'b str,
+}
+
+impl<'a> LoxoneIndex<'a> {
+    /// Builds the index for the given structure file.
+    pub fn new(app: &'a LoxoneApp3) -> Self {
+        let mut index = Self {
+            entries: HashMap::new(),
+            states: HashMap::new(),
+            global_states: HashMap::new(),
+            message_center_states: HashSet::new(),
+            by_room: HashMap::new(),
+            by_cat: HashMap::new(),
+            by_type: HashMap::new(),
+        };
+        for (state_name, state_uuid) in app.global_states.state_uuids() {
+            index.global_states.insert(state_uuid.clone(), state_name.to_owned());
+        }
+        for message in app.message_center.values() {
+            index.message_center_states.extend(message.states.values().map(String::as_str));
+        }
+        for control in app.controls.values() {
+            index.insert(LoxoneIndexEntry {
+                uuid: &control.uuid_action,
+                name: &control.name,
+                controller: &control.controller,
+                parent: None,
+                room: control.room.as_ref(),
+                cat: control.cat.as_ref(),
+                is_favorite: control.is_favorite,
+            });
+        }
+        index
+    }
+
+    fn insert(&mut self, entry: LoxoneIndexEntry<'a>) {
+        for (state_name, state_uuid) in entry.controller.state_uuids() {
+            self.states.insert(state_uuid.clone(), (entry.uuid, state_name.to_owned()));
+        }
+        if let Some(room) = entry.room {
+            self.by_room.entry(room).or_default().push(entry.uuid);
+        }
+        if let Some(cat) = entry.cat {
+            self.by_cat.entry(cat).or_default().push(entry.uuid);
+        }
+        self.by_type.entry(entry.controller.type_name()).or_default().push(entry.uuid);
+        for sub_control in entry.controller.sub_controls().into_iter().flat_map(HashMap::values) {
+            self.insert(LoxoneIndexEntry {
+                uuid: &sub_control.uuid_action,
+                name: &sub_control.name,
+                controller: &sub_control.controller,
+                parent: Some(entry.uuid),
+                room: entry.room,
+                cat: entry.cat,
+                is_favorite: sub_control.is_favorite,
+            });
+        }
+        self.entries.insert(entry.uuid, entry);
+    }
+
+    /// Returns the control or sub-control with the given UUID.
+    pub fn get(&self, uuid: &str) -> Option<&LoxoneIndexEntry<'a>> {
+        self.entries.get(uuid)
+    }
+
+    /// Returns all controls and sub-controls.
+    pub fn iter(&self) -> impl Iterator<Item = &LoxoneIndexEntry<'a>> {
+        self.entries.values()
+    }
+
+    /// Returns the controls in the given room.
+    pub fn by_room(&self, room: &str) -> Vec<&LoxoneIndexEntry<'a>> {
+        self.lookup(&self.by_room, room)
+    }
+
+    /// Returns the controls in the given category.
+    pub fn by_category(&self, cat: &str) -> Vec<&LoxoneIndexEntry<'a>> {
+        self.lookup(&self.by_cat, cat)
+    }
+
+    /// Returns the controls with the given controller type, e.g. `"Jalousie"`.
+    pub fn by_type(&self, type_name: &str) -> Vec<&LoxoneIndexEntry<'a>> {
+        self.lookup(&self.by_type, type_name)
+    }
+
+    /// Returns the controls marked as favorite.
+    pub fn favorites(&self) -> Vec<&LoxoneIndexEntry<'a>> {
+        self.entries.values().filter(|entry| entry.is_favorite).collect()
+    }
+
+    /// Returns the controls whose name equals `name`, ignoring case.
+    pub fn by_name(&self, name: &str) -> Vec<&LoxoneIndexEntry<'a>> {
+        let name = name.to_lowercase();
+        self.entries.values().filter(|entry| entry.name.to_lowercase() == name).collect()
+    }
+
+    /// Returns the controls whose name contains every word of `query`, ignoring case.
+    ///
+    /// Results are ordered by name length, so closer matches come first.
+    pub fn search(&self, query: &str) -> Vec<&LoxoneIndexEntry<'a>> {
+        let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        let mut matches: Vec<&LoxoneIndexEntry<'a>> = self.entries.values().filter(|entry| {
+            let name = entry.name.to_lowercase();
+            words.iter().all(|word| name.contains(word.as_str()))
+        }).collect();
+        matches.sort_by(|a, b| a.name.len().cmp(&b.name.len()).then_with(|| a.name.cmp(b.name)));
+        matches
+    }
+
+    /// Returns the control or sub-control owning the given state UUID, along with the state name.
+    pub fn state_owner(&self, state: &str) -> Option<LoxoneStateOwner<'a, '_>> {
+        let (uuid, state_name) = self.states.get(state)?;
+        Some(LoxoneStateOwner { control: self.entries.get(uuid)?, state_name })
+    }
+
+    /// Decodes a text state into a typed value, based on its owning control and state name.
+    ///
+    /// Returns `Ok(None)` if the state is not a text state or its UUID is unknown.
+    pub fn decode_state(&self, uuid: &str, state: &LoxoneState) -> Result<Option<LoxoneTextState>, TextStateError> {
+        let text = match state {
+            LoxoneState::Text(text, _) => text,
+            _ => return Ok(None),
+        };
+        if let Some(state_name) = self.global_states.get(uuid) {
+            return decode_global_text_state(state_name, text).map(Some);
+        }
+        if self.message_center_states.contains(uuid) && text.starts_with(&['{', '['][..]) {
+            return decode_message_center_text_state(text).map(Some);
+        }
+        match self.state_owner(uuid) {
+            Some(owner) => decode_text_state(owner.control.controller.type_name(), owner.state_name, text).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn lookup(&self, map: &HashMap<&'a str, Vec<&'a str>>, key: &str) -> Vec<&LoxoneIndexEntry<'a>> {
+        map.get(key).into_iter().flatten().filter_map(|uuid| self.entries.get(uuid)).collect()
+    }
+}
+
+impl LoxoneApp3 {
+    /// Returns an indexed view over the structure file.
+    pub fn index(&self) -> LoxoneIndex<'_> {
+        LoxoneIndex::new(self)
+    }
+}
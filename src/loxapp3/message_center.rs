@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::epoch::LoxoneTimestamp;
+use crate::loxapp3::{LoxoneMessage, LoxoneMutation, LoxoneUUID};
+
+/// Content of the message center (system status) state.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoxoneMessageCenter {
+    #[serde(default)]
+    pub entries: Vec<LoxoneMessageEntry>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// System message reported by the Miniserver, e.g. an offline extension or a low battery.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneMessageEntry {
+    pub entry_uuid: LoxoneUUID,
+    pub event_id: Option<i32>,
+    pub source_uuid: Option<LoxoneUUID>,
+    pub severity: LoxoneMessageSeverity,
+    pub title: Option<String>,
+    pub desc: Option<String>,
+    /// Name of the affected device or control.
+    pub affected_name: Option<String>,
+    pub installation_place: Option<String>,
+    pub room_uuid: Option<LoxoneUUID>,
+    /// Occurrences of the message, oldest first.
+    #[serde(default)]
+    pub timestamps: Vec<LoxoneTimestamp>,
+    pub read_at: Option<LoxoneTimestamp>,
+    pub confirmed_at: Option<LoxoneTimestamp>,
+    #[serde(default)]
+    pub is_historic: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Severity of a system message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum LoxoneMessageSeverity {
+    Info,
+    Warning,
+    Error,
+    SystemError,
+    Unknown(u8),
+}
+
+impl LoxoneMessageCenter {
+    /// Returns the entries that are still active, i.e. not historic.
+    pub fn active(&self) -> impl Iterator<Item = &LoxoneMessageEntry> {
+        self.entries.iter().filter(|entry| !entry.is_historic)
+    }
+
+    /// Returns the active entries that haven't been read yet.
+    pub fn unread(&self) -> impl Iterator<Item = &LoxoneMessageEntry> {
+        self.active().filter(|entry| entry.read_at.is_none())
+    }
+}
+
+impl LoxoneMessageEntry {
+    /// Returns the last occurrence of the message.
+    pub fn last_seen(&self) -> Option<LoxoneTimestamp> {
+        self.timestamps.iter().max().copied()
+    }
+}
+
+impl LoxoneMessage {
+    pub fn confirm(entry_uuid: &str) -> LoxoneMutation { format!("confirm/{}", entry_uuid) }
+    pub fn mark_read(entry_uuid: &str) -> LoxoneMutation { format!("setReadAt/{}", entry_uuid) }
+}
+
+impl From<u8> for LoxoneMessageSeverity {
+    fn from(val: u8) -> Self {
+        match val {
+            1 => Self::Info,
+            2 => Self::Warning,
+            3 => Self::Error,
+            4 => Self::SystemError,
+            val => Self::Unknown(val),
+        }
+    }
+}
+
+impl From<LoxoneMessageSeverity> for u8 {
+    fn from(severity: LoxoneMessageSeverity) -> Self {
+        match severity {
+            LoxoneMessageSeverity::Info => 1,
+            LoxoneMessageSeverity::Warning => 2,
+            LoxoneMessageSeverity::Error => 3,
+            LoxoneMessageSeverity::SystemError => 4,
+            LoxoneMessageSeverity::Unknown(val) => val,
+        }
+    }
+}
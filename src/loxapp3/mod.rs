@@ -1,7 +1,21 @@
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
 
+pub mod color;
 pub mod controllers;
+pub mod diff;
+pub mod global_states;
+pub mod index;
+pub mod message_center;
+pub mod modes;
+pub mod schedule;
+pub mod states;
+pub mod statistics;
+pub mod weather;
 
 use controllers::*;
 
@@ -11,8 +25,14 @@ pub type LoxoneUUID = String;
 /// Command description.
 pub type LoxoneMutation = String;
 
+/// States of a controller or of the Miniserver, by state name.
+pub trait LoxoneStates {
+    /// Returns the state names and UUIDs, including states that are not modeled.
+    fn state_uuids(&self) -> Vec<(&str, &LoxoneUUID)>;
+}
+
 /// State that may change over time. 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum LoxoneState {
     Value(f64),
     Text(String, LoxoneUUID),
@@ -21,24 +41,31 @@ pub enum LoxoneState {
 }
 
 /// Miniserver global configuration aka. “structure file”.
-#[derive(Debug, Deserialize)]
+///
+/// Serializing omits missing optional fields and defaulted fields at their default value.
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneApp3 {
- // TODO autopilot
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub autopilot: HashMap<LoxoneUUID, LoxoneAutopilotRule>,
     pub cats: HashMap<LoxoneUUID, LoxoneCategory>,
     pub controls: HashMap<LoxoneUUID, LoxoneControl>,
     pub global_states: LoxoneGlobalStates,
     pub last_modified: String,
     pub message_center: HashMap<LoxoneUUID, LoxoneMessage>,
     pub ms_info: LoxoneMiniserverInfo,
+    #[serde(deserialize_with = "deserialize_int_keys")]
     pub operating_modes: HashMap<i8, String>,
     pub rooms: HashMap<LoxoneUUID, LoxoneRoom>,
     pub times: HashMap<String, LoxoneTime>,
- // TODO weather_server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weather_server: Option<LoxoneWeatherServer>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Category that is used to group controls logically.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneCategory {
     pub color: String,
@@ -47,46 +74,116 @@ pub struct LoxoneCategory {
     pub name: String,
     pub r#type: String,
     pub uuid: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Control that is used to represent sensors and actuators.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneControl {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cat: Option<LoxoneUUID>,
     #[serde(flatten)]
     pub controller: LoxoneController,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default_icon: Option<String>,
     pub default_rating: u8,
- // TODO has_control_notes
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_control_notes: bool,
     pub is_favorite: bool,
     pub is_secured: bool,
     pub name: String,
- // TODO restriction
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub restrictions: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub room: Option<LoxoneUUID>,
- // TODO secured_details
- // TODO statistics
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub secured_details: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistic: Option<LoxoneStatistic>,
     pub uuid_action: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneSubControl {
     #[serde(flatten)]
     pub controller: LoxoneController,
     pub default_rating: u8,
- // TODO has_control_notes
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub has_control_notes: bool,
     pub is_favorite: bool,
     pub is_secured: bool,
     pub name: String,
- // TODO restriction
- // TODO secured_details
- // TODO statistics
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub restrictions: u32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub secured_details: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistic: Option<LoxoneStatistic>,
+    pub uuid_action: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Automatic rule (“Autopilot”) created by users in the app.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneAutopilotRule {
+    pub name: String,
     pub uuid_action: LoxoneUUID,
+    pub states: LoxoneAutopilotStates,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoxoneAutopilotStates {
+    pub changed: LoxoneUUID,
+    pub history: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Statistic recording configuration of a control.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoxoneStatistic {
+    pub frequency: LoxoneStatisticFrequency,
+    pub outputs: Vec<LoxoneStatisticOutput>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Interval in which statistic values are recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum LoxoneStatisticFrequency {
+    None,
+    EveryChange,
+    Average1Min,
+    Average5Min,
+    Average10Min,
+    Average30Min,
+    Average60Min,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneStatisticOutput {
+    pub id: u8,
+    pub name: String,
+    pub format: String,
+    pub uuid: LoxoneUUID,
+    pub visu_type: u8,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Global states that affect the whole Miniserver.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneGlobalStates {
     pub sunset: LoxoneUUID,
@@ -102,19 +199,23 @@ pub struct LoxoneGlobalStates {
     pub past_tasks: LoxoneUUID,
     pub modifications: LoxoneUUID,
     pub user_settings: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// System status message.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneMessage {
     pub name: String,
     pub uuid_action: LoxoneUUID,
     pub states: HashMap<String, LoxoneUUID>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Static informations on the Miniserver and it’s configuration.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneMiniserverInfo {
     pub serial_nr: String,
@@ -136,20 +237,24 @@ pub struct LoxoneMiniserverInfo {
     pub current_user: LoxoneUser,
     pub device_monitor: LoxoneUUID,
     pub language_code: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneUser {
     pub uuid: LoxoneUUID,
     pub name: String,
     pub is_admin: bool,
     pub change_password: bool,
-    pub user_rights: u32
+    pub user_rights: u32,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Room that is used to group controls based on their location.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LoxoneRoom {
     pub uuid: LoxoneUUID,
@@ -158,80 +263,168 @@ pub struct LoxoneRoom {
     pub default_rating: u8,
     pub is_favorite: bool,
     pub r#type: u8,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct LoxoneTime {
     pub id: u16,
     pub name: String,
     pub analog: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Weather service configuration.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneWeatherServer {
+    pub states: LoxoneWeatherServerStates,
+    pub format: LoxoneWeatherFormat,
+    #[serde(deserialize_with = "deserialize_int_keys")]
+    pub weather_type_texts: HashMap<u16, String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", deserialize_with = "deserialize_int_keys")]
+    pub weather_field_types: HashMap<u16, LoxoneWeatherFieldType>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoxoneWeatherServerStates {
+    pub actual: LoxoneUUID,
+    pub forecast: LoxoneUUID,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(tag = "type")]
-pub enum LoxoneController {
-    AalEmergency,
-    AalSmartAlarm,
-    Alarm,
-    AlarmChain,
-    AlarmClock,
-    AudioZone,
-    CarCharger,
-    CentralAlarm,
-    CentralAudioZone,
-    CentralGate,
-    CentralJalousie,
+/// Format strings used to display weather values.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneWeatherFormat {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub barometric_pressure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precipitation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative_humidity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solar_radiation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wind_speed: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoxoneWeatherFieldType {
+    pub id: u16,
+    pub name: String,
+    pub analog: bool,
+    pub unit: String,
+    pub format: String,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Defines [`LoxoneController`] with a variant per modeled controller type, named after its structure file type name.
+macro_rules! controllers {
+    ($($variant:ident($controller:ty),)*) => {
+        /// Controller type specific configuration of a control.
+        ///
+        /// Controls with a type that is not modeled by this crate, or with content that
+        /// does not match the model of their type, deserialize into
+        /// [`LoxoneController::Unknown`], keeping their raw JSON.
+        #[derive(Debug, Deserialize, Serialize)]
+        #[serde(tag = "type", remote = "Self")]
+        pub enum LoxoneController {
+            $($variant($controller),)*
+            #[serde(skip)]
+            Unknown {
+                type_name: String,
+                raw: serde_json::Value,
+            },
+        }
+
+        /// Type names of the modeled controllers.
+        const CONTROLLER_TYPES: &[&str] = &[$(stringify!($variant),)*];
+
+        impl LoxoneController {
+            /// Returns the structure file type name of the controller.
+            pub fn type_name(&self) -> &str {
+                match self {
+                    $(Self::$variant(_) => stringify!($variant),)*
+                    Self::Unknown { type_name, .. } => type_name,
+                }
+            }
+        }
+    };
+}
+
+controllers! {
+    AalEmergency(AalEmergency),
+    AalSmartAlarm(AalSmartAlarm),
+    Alarm(Alarm),
+    AlarmChain(AlarmChain),
+    AlarmClock(AlarmClock),
+    AudioZone(AudioZone),
+    CarCharger(CarCharger),
+    CentralAlarm(CentralAlarm),
+    CentralAudioZone(CentralAudioZone),
+    CentralGate(CentralGate),
+    CentralJalousie(CentralJalousie),
     CentralLightController(CentralLightController),
     ClimateController(ClimateController),
     ColorPicker(ColorPicker),
     ColorPickerV2(ColorPickerV2),
-    Daytimer,
+    Daytimer(Daytimer),
     Dimmer(Dimmer),
-    FanController,
-    Fronius,
-    Gate,
-    Heatmixer,
-    Hourcounter,
+    FanController(FanController),
+    Fronius(Fronius),
+    Gate(Gate),
+    Heatmixer(Heatmixer),
+    Hourcounter(Hourcounter),
     InfoOnlyAnalog(InfoOnlyAnalog),
     InfoOnlyDigital(InfoOnlyDigital),
-    IntelligentRoomControllerv2,
-    IntelligentRoomControllerIntercom,
+    Intercom(Intercom),
     IRCV2Daytimer(IRCV2Daytimer),
-    IRoomController,
-    IRoomControllerV2(IRoomControllerV2), 
-    Jalousie,
+    IRoomController(IRoomController),
+    IRoomControllerV2(IRoomControllerV2),
+    Jalousie(Jalousie),
     NfcCodeTouch(NfcCodeTouch),
-    LightController,
+    LightController(LightController),
     LightControllerV2(LightControllerV2),
-    LightsceneRGB,
-    MailBox,
-    Meter,
-    PoolController,
-    Pushbutton,
-    Radio,
-    Remote,
-    Sauna,
+    LightsceneRGB(LightsceneRGB),
+    MailBox(MailBox),
+    Meter(Meter),
+    PoolController(PoolController),
+    Pushbutton(Pushbutton),
+    Radio(Radio),
+    Remote(Remote),
+    Sauna(Sauna),
     Slider(Slider),
     SmokeAlarm(SmokeWaterAlarm),
     WaterAlarm(SmokeWaterAlarm),
-    SolarPumpController,
-    SteakThermo,
+    SolarPumpController(SolarPumpController),
+    SteakThermo(SteakThermo),
     Switch(Switch),
-    SystemScheme,
-    TextState,
-    TextInput,
-    TimedSwitch,
-    Tracker,
-    UpDownLeftRight,
-    ValueSelector,
-    Ventilation,
-    Webpage,
-    Window,
-    WindowMonitor,
+    SystemScheme(SystemScheme),
+    TextState(TextState),
+    TextInput(TextInput),
+    TimedSwitch(TimedSwitch),
+    Tracker(Tracker),
+    UpDownLeftRight(UpDownLeftRight),
+    ValueSelector(ValueSelector),
+    Ventilation(Ventilation),
+    Webpage(Webpage),
+    Window(Window),
+    WindowMonitor(WindowMonitor),
 }
 
 /// Day timer event entry.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LoxoneDaytimerEntry {
     pub mode: i32,
     pub from: i32,
@@ -241,7 +434,7 @@ pub struct LoxoneDaytimerEntry {
 }
 
 /// Weather event entry.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LoxoneWeatherEntry {
     pub timestamp: i32,
     pub weather_type: i32,
@@ -253,5 +446,253 @@ pub struct LoxoneWeatherEntry {
     pub dew_point: f64,
     pub precipitation: f64,
     pub wind_speed: f64,
-    pub barometic_pressure: f64,
-}
\ No newline at end of file
+    pub barometric_pressure: f64,
+}
+impl LoxoneApp3 {
+    /// Returns the UUID and type name of every control and sub-control with an unknown controller type.
+    pub fn unknown_controllers(&self) -> Vec<(&LoxoneUUID, &str)> {
+        let mut unknown = Vec::new();
+        for control in self.controls.values() {
+            collect_unknown_controllers(&control.uuid_action, &control.controller, &mut unknown);
+        }
+        unknown
+    }
+}
+
+impl LoxoneStates for LoxoneGlobalStates {
+    fn state_uuids(&self) -> Vec<(&str, &LoxoneUUID)> {
+        let modeled = [
+            ("sunset", &self.sunset),
+            ("sunrise", &self.sunrise),
+            ("favColorSequences", &self.fav_color_sequences),
+            ("favColors", &self.fav_colors),
+            ("notifications", &self.notifications),
+            ("miniserverTime", &self.miniserver_time),
+            ("liveSearch", &self.live_search),
+            ("hasInternet", &self.has_internet),
+            ("operatingMode", &self.operating_mode),
+            ("plannedTasks", &self.planned_tasks),
+            ("pastTasks", &self.past_tasks),
+            ("modifications", &self.modifications),
+            ("userSettings", &self.user_settings),
+        ];
+        modeled.iter().copied().chain(unmodeled_states(&self.other)).collect()
+    }
+}
+
+impl LoxoneStates for HashMap<String, LoxoneUUID> {
+    fn state_uuids(&self) -> Vec<(&str, &LoxoneUUID)> {
+        self.iter().map(|(name, uuid)| (name.as_str(), uuid)).collect()
+    }
+}
+
+impl TryFrom<u8> for LoxoneStatisticFrequency {
+    type Error = String;
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::None),
+            1 => Ok(Self::EveryChange),
+            2 => Ok(Self::Average1Min),
+            3 => Ok(Self::Average5Min),
+            4 => Ok(Self::Average10Min),
+            5 => Ok(Self::Average30Min),
+            6 => Ok(Self::Average60Min),
+            _ => Err(format!("invalid statistic frequency {}", val)),
+        }
+    }
+}
+
+impl From<LoxoneStatisticFrequency> for u8 {
+    fn from(frequency: LoxoneStatisticFrequency) -> Self {
+        frequency as u8
+    }
+}
+
+impl LoxoneController {
+    /// Returns the state names and UUIDs of the controller, including states that are not modeled.
+    pub fn state_uuids(&self) -> Vec<(&str, &LoxoneUUID)> {
+        match self {
+            Self::AalEmergency(controller) => controller.states.state_uuids(),
+            Self::AalSmartAlarm(controller) => controller.states.state_uuids(),
+            Self::Alarm(controller) => controller.states.state_uuids(),
+            Self::AlarmChain(controller) => controller.states.state_uuids(),
+            Self::AlarmClock(controller) => controller.states.state_uuids(),
+            Self::AudioZone(controller) => controller.states.state_uuids(),
+            Self::CarCharger(controller) => controller.states.state_uuids(),
+            Self::CentralAlarm(controller) => controller.states.state_uuids(),
+            Self::ClimateController(controller) => controller.states.state_uuids(),
+            Self::ColorPicker(controller) => controller.states.state_uuids(),
+            Self::ColorPickerV2(controller) => controller.states.state_uuids(),
+            Self::Daytimer(controller) => controller.states.state_uuids(),
+            Self::Dimmer(controller) => controller.states.state_uuids(),
+            Self::FanController(controller) => controller.states.state_uuids(),
+            Self::Fronius(controller) => controller.states.state_uuids(),
+            Self::Gate(controller) => controller.states.state_uuids(),
+            Self::Heatmixer(controller) => controller.states.state_uuids(),
+            Self::Hourcounter(controller) => controller.states.state_uuids(),
+            Self::InfoOnlyAnalog(controller) => controller.states.state_uuids(),
+            Self::InfoOnlyDigital(controller) => controller.states.state_uuids(),
+            Self::Intercom(controller) => controller.states.state_uuids(),
+            Self::IRCV2Daytimer(controller) => controller.states.state_uuids(),
+            Self::IRoomController(controller) => controller.states.state_uuids(),
+            Self::IRoomControllerV2(controller) => controller.states.state_uuids(),
+            Self::Jalousie(controller) => controller.states.state_uuids(),
+            Self::NfcCodeTouch(controller) => controller.states.state_uuids(),
+            Self::LightController(controller) => controller.states.state_uuids(),
+            Self::LightControllerV2(controller) => controller.states.state_uuids(),
+            Self::LightsceneRGB(controller) => controller.states.state_uuids(),
+            Self::MailBox(controller) => controller.states.state_uuids(),
+            Self::Meter(controller) => controller.states.state_uuids(),
+            Self::PoolController(controller) => controller.states.state_uuids(),
+            Self::Pushbutton(controller) => controller.states.state_uuids(),
+            Self::Radio(controller) => controller.states.state_uuids(),
+            Self::Remote(controller) => controller.states.state_uuids(),
+            Self::Sauna(controller) => controller.states.state_uuids(),
+            Self::Slider(controller) => controller.states.state_uuids(),
+            Self::SmokeAlarm(controller) => controller.states.state_uuids(),
+            Self::WaterAlarm(controller) => controller.states.state_uuids(),
+            Self::SolarPumpController(controller) => controller.states.state_uuids(),
+            Self::SteakThermo(controller) => controller.states.state_uuids(),
+            Self::Switch(controller) => controller.states.state_uuids(),
+            Self::SystemScheme(controller) => controller.states.state_uuids(),
+            Self::TextState(controller) => controller.states.state_uuids(),
+            Self::TextInput(controller) => controller.states.state_uuids(),
+            Self::TimedSwitch(controller) => controller.states.state_uuids(),
+            Self::Tracker(controller) => controller.states.state_uuids(),
+            Self::UpDownLeftRight(controller) => controller.states.state_uuids(),
+            Self::ValueSelector(controller) => controller.states.state_uuids(),
+            Self::Ventilation(controller) => controller.states.state_uuids(),
+            Self::Window(controller) => controller.states.state_uuids(),
+            Self::WindowMonitor(controller) => controller.states.state_uuids(),
+            Self::Unknown { raw, .. } => raw.get("states")
+                .and_then(serde_json::Value::as_object)
+                .map(|states| unmodeled_states(states).collect())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the state UUIDs of the controller by state name.
+    pub fn states(&self) -> HashMap<String, LoxoneUUID> {
+        self.state_uuids().into_iter().map(|(name, uuid)| (name.to_owned(), uuid.clone())).collect()
+    }
+
+    /// Returns the sub-controls of the controller, if it has any.
+    pub fn sub_controls(&self) -> Option<&HashMap<LoxoneUUID, LoxoneSubControl>> {
+        match self {
+            Self::Intercom(controller) => Some(&controller.sub_controls),
+            Self::IRoomController(controller) => Some(&controller.sub_controls),
+            Self::IRoomControllerV2(controller) => Some(&controller.sub_controls),
+            Self::LightController(controller) => Some(&controller.sub_controls),
+            Self::LightControllerV2(controller) => Some(&controller.sub_controls),
+            Self::PoolController(controller) => Some(&controller.sub_controls),
+            Self::SmokeAlarm(controller) => Some(&controller.sub_controls),
+            Self::WaterAlarm(controller) => Some(&controller.sub_controls),
+            Self::SystemScheme(controller) => Some(&controller.sub_controls),
+            Self::Ventilation(controller) => Some(&controller.sub_controls),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LoxoneController {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Only take the controller fields, so the remaining fields of a flattened control end up in its `other` map.
+        let raw = serde_json::Value::Object(deserializer.deserialize_struct("LoxoneController", CONTROLLER_FIELDS, ControllerFieldsVisitor)?);
+        let type_name = raw.get("type").and_then(serde_json::Value::as_str).ok_or_else(|| de::Error::missing_field("type"))?.to_owned();
+        if !CONTROLLER_TYPES.contains(&type_name.as_str()) {
+            return Ok(Self::Unknown { type_name, raw });
+        }
+        match LoxoneController::deserialize(&raw) {
+            Ok(controller) => Ok(controller),
+            Err(err) => {
+                tracing::warn!(%type_name, %err, "controller does not match its model, keeping it as unknown");
+                Ok(Self::Unknown { type_name, raw })
+            },
+        }
+    }
+}
+
+impl Serialize for LoxoneController {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Unknown { raw, .. } => raw.serialize(serializer),
+            controller => LoxoneController::serialize(controller, serializer),
+        }
+    }
+}
+
+const CONTROLLER_FIELDS: &[&str] = &["type", "details", "states", "subControls"];
+
+struct ControllerFieldsVisitor;
+
+impl<'de> de::Visitor<'de> for ControllerFieldsVisitor {
+    type Value = serde_json::Map<String, serde_json::Value>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("controller fields")
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = serde_json::Map::new();
+        while let Some((key, val)) = map.next_entry()? {
+            fields.insert(key, val);
+        }
+        Ok(fields)
+    }
+}
+
+/// Deserializes a map with integer keys, which are encoded as JSON strings.
+fn deserialize_int_keys<'de, D, K, V>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: FromStr + Eq + Hash,
+    K::Err: fmt::Display,
+    V: Deserialize<'de>,
+{
+    HashMap::<String, V>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(key, val)| key.parse().map(|key| (key, val)).map_err(de::Error::custom))
+        .collect()
+}
+
+/// Returns true if the value equals its default, so serializing it can be skipped.
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Returns the states of a flattened `other` map, which are the entries with a UUID string value.
+pub(crate) fn unmodeled_states(other: &serde_json::Map<String, serde_json::Value>) -> impl Iterator<Item = (&str, &LoxoneUUID)> {
+    other.iter().filter_map(|(name, uuid)| match uuid {
+        serde_json::Value::String(uuid) => Some((name.as_str(), uuid)),
+        _ => None,
+    })
+}
+
+fn collect_unknown_controllers<'a>(uuid: &'a LoxoneUUID, controller: &'a LoxoneController, unknown: &mut Vec<(&'a LoxoneUUID, &'a str)>) {
+    if let LoxoneController::Unknown { type_name, .. } = controller {
+        unknown.push((uuid, type_name));
+    }
+    for sub_control in controller.sub_controls().into_iter().flat_map(HashMap::values) {
+        collect_unknown_controllers(&sub_control.uuid_action, &sub_control.controller, unknown);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_controller_type() {
+        let app: serde_json::Value = serde_json::from_str(include_str!("../../tests/fixtures/LoxAPP3.json")).unwrap();
+        let controls = app["controls"].as_object().unwrap();
+        for type_name in CONTROLLER_TYPES {
+            let raw = controls.values().find(|control| control["type"] == *type_name).unwrap_or_else(|| panic!("no {} control in the fixture", type_name));
+            let controller: LoxoneController = serde_json::from_value(raw.clone()).unwrap();
+            assert!(!matches!(controller, LoxoneController::Unknown { .. }), "{} does not match its model", type_name);
+            assert_eq!(controller.type_name(), *type_name);
+            assert_eq!(serde_json::to_value(&controller).unwrap()["type"], *type_name);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::loxapp3::{LoxoneApp3, LoxoneMutation, LoxoneState, LoxoneTime, LoxoneUUID};
+
+/// Operating mode of the Miniserver, e.g. "Holiday" or "Workday".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoxoneOperatingMode<'a> {
+    pub id: i8,
+    pub name: &'a str,
+}
+
+/// Calendar entry activating an operating mode, as returned by `calendargetentries`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneCalendarEntry {
+    pub uuid: LoxoneUUID,
+    pub name: String,
+    pub operating_mode: i8,
+    /// Recurrence, decoded from `calMode` and its parameter fields.
+    #[serde(flatten)]
+    pub cal_mode: LoxoneCalendarMode,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Recurrence of a calendar entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "RawCalendarMode", into = "RawCalendarMode")]
+pub enum LoxoneCalendarMode {
+    /// Every year on the given month and day.
+    Yearly { month: u32, day: u32 },
+    /// Every year, offset in days from Easter Sunday.
+    Easter { offset: i16 },
+    /// Once on the given date.
+    Date(NaiveDate),
+    /// Once from `start` to `end`, both inclusive.
+    Period { start: NaiveDate, end: NaiveDate },
+    /// Every year on the nth weekday of the given month.
+    Weekday { weekday: Weekday, nth: u8, month: u32 },
+}
+
+/// `calMode` of a calendar entry and the parameter fields used by it.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RawCalendarMode {
+    cal_mode: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_offset: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weekday: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nth: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    month: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day: Option<u32>,
+}
+
+impl LoxoneApp3 {
+    /// Returns the operating modes, ordered by id.
+    pub fn operating_mode_list(&self) -> Vec<LoxoneOperatingMode<'_>> {
+        let mut modes: Vec<LoxoneOperatingMode<'_>> = self.operating_modes.iter().map(|(id, name)| LoxoneOperatingMode { id: *id, name }).collect();
+        modes.sort_by_key(|mode| mode.id);
+        modes
+    }
+
+    /// Returns the operating mode with the given id.
+    pub fn operating_mode(&self, id: i8) -> Option<LoxoneOperatingMode<'_>> {
+        self.operating_modes.get(&id).map(|name| LoxoneOperatingMode { id, name })
+    }
+
+    /// Returns the current operating mode from the `operatingMode` global state.
+    pub fn current_operating_mode(&self, states: &HashMap<LoxoneUUID, LoxoneState>) -> Option<LoxoneOperatingMode<'_>> {
+        match states.get(&self.global_states.operating_mode)? {
+            LoxoneState::Value(id) => self.operating_mode(*id as i8),
+            _ => None,
+        }
+    }
+
+    /// Returns the times of day usable in daytimer schedules, ordered by id.
+    pub fn times_of_day(&self) -> Vec<&LoxoneTime> {
+        let mut times: Vec<&LoxoneTime> = self.times.values().collect();
+        times.sort_by_key(|time| time.id);
+        times
+    }
+}
+
+impl LoxoneCalendarEntry {
+    pub fn get_entries() -> LoxoneMutation { String::from("calendargetentries") }
+    pub fn create(name: &str, operating_mode: i8, cal_mode: &LoxoneCalendarMode) -> LoxoneMutation { format!("calendarcreateentry/{}/{}/{}", utf8_percent_encode(name, NON_ALPHANUMERIC), operating_mode, cal_mode.to_cmd()) }
+    pub fn update(uuid: &str, name: &str, operating_mode: i8, cal_mode: &LoxoneCalendarMode) -> LoxoneMutation { format!("calendarupdateentry/{}/{}/{}/{}", uuid, utf8_percent_encode(name, NON_ALPHANUMERIC), operating_mode, cal_mode.to_cmd()) }
+    pub fn delete(uuid: &str) -> LoxoneMutation { format!("calendardeleteentry/{}", uuid) }
+}
+
+impl LoxoneCalendarMode {
+    /// Returns the `calMode` id and its parameters, separated by slashes.
+    fn to_cmd(self) -> String {
+        match self {
+            Self::Yearly { month, day } => format!("0/{}/{}", month, day),
+            Self::Easter { offset } => format!("1/{}", offset),
+            Self::Date(date) => format!("2/{}/{}/{}", date.year(), date.month(), date.day()),
+            Self::Period { start, end } => format!("3/{}/{}/{}/{}/{}/{}", start.year(), start.month(), start.day(), end.year(), end.month(), end.day()),
+            Self::Weekday { weekday, nth, month } => format!("4/{}/{}/{}", weekday.num_days_from_monday(), nth, month),
+        }
+    }
+}
+
+impl TryFrom<RawCalendarMode> for LoxoneCalendarMode {
+    type Error = String;
+
+    fn try_from(raw: RawCalendarMode) -> Result<Self, Self::Error> {
+        let missing = |field: &str| format!("missing {} of calendar mode {}", field, raw.cal_mode);
+        match raw.cal_mode {
+            0 => Ok(Self::Yearly {
+                month: raw.month.ok_or_else(|| missing("month"))?,
+                day: raw.day.ok_or_else(|| missing("day"))?,
+            }),
+            1 => Ok(Self::Easter { offset: raw.event_offset.ok_or_else(|| missing("eventOffset"))? }),
+            2 => Ok(Self::Date(raw.start_date.ok_or_else(|| missing("startDate"))?)),
+            3 => Ok(Self::Period {
+                start: raw.start_date.ok_or_else(|| missing("startDate"))?,
+                end: raw.end_date.ok_or_else(|| missing("endDate"))?,
+            }),
+            4 => Ok(Self::Weekday {
+                weekday: raw.weekday.and_then(weekday_from_monday).ok_or_else(|| missing("weekday"))?,
+                nth: raw.nth.ok_or_else(|| missing("nth"))?,
+                month: raw.month.ok_or_else(|| missing("month"))?,
+            }),
+            cal_mode => Err(format!("invalid calendar mode {}", cal_mode)),
+        }
+    }
+}
+
+impl From<LoxoneCalendarMode> for RawCalendarMode {
+    fn from(mode: LoxoneCalendarMode) -> Self {
+        match mode {
+            LoxoneCalendarMode::Yearly { month, day } => Self { cal_mode: 0, month: Some(month), day: Some(day), ..Self::default() },
+            LoxoneCalendarMode::Easter { offset } => Self { cal_mode: 1, event_offset: Some(offset), ..Self::default() },
+            LoxoneCalendarMode::Date(date) => Self { cal_mode: 2, start_date: Some(date), ..Self::default() },
+            LoxoneCalendarMode::Period { start, end } => Self { cal_mode: 3, start_date: Some(start), end_date: Some(end), ..Self::default() },
+            LoxoneCalendarMode::Weekday { weekday, nth, month } => Self { cal_mode: 4, weekday: Some(weekday.num_days_from_monday() as u8), nth: Some(nth), month: Some(month), ..Self::default() },
+        }
+    }
+}
+
+fn weekday_from_monday(days: u8) -> Option<Weekday> {
+    [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun].get(usize::from(days)).copied()
+}
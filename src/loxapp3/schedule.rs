@@ -0,0 +1,200 @@
+use chrono::{NaiveTime, Timelike};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use thiserror::Error;
+
+use crate::loxapp3::{LoxoneDaytimerEntry, LoxoneMutation};
+use crate::loxapp3::controllers::{Daytimer, IRCV2Daytimer};
+
+/// Typed schedule of a daytimer control.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoxoneSchedule {
+    pub default_value: f64,
+    pub entries: Vec<LoxoneScheduleEntry>,
+}
+
+/// Time span of a schedule during which the daytimer outputs `value`.
+///
+/// A `to` time of midnight (`00:00`) denotes the end of the day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoxoneScheduleEntry {
+    pub mode: i32,
+    pub mode_name: Option<String>,
+    pub from: NaiveTime,
+    pub to: NaiveTime,
+    pub need_activate: bool,
+    pub value: f64,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ScheduleError {
+    #[error("invalid time {0} minutes since midnight")]
+    InvalidTime(i32),
+    #[error("entry {0} ends before it starts")]
+    EmptyEntry(usize),
+    #[error("entries {0} and {1} overlap")]
+    Overlap(usize, usize),
+}
+
+impl LoxoneSchedule {
+    /// Creates a schedule from daytimer state entries, resolving mode ids through the `operating_modes` of the structure file.
+    pub fn from_entries(entries: &[LoxoneDaytimerEntry], default_value: f64, operating_modes: &HashMap<i8, String>) -> Result<Self, ScheduleError> {
+        let entries = entries.iter().map(|entry| Ok(LoxoneScheduleEntry {
+            mode: entry.mode,
+            mode_name: i8::try_from(entry.mode).ok().and_then(|mode| operating_modes.get(&mode)).cloned(),
+            from: minutes_to_time(entry.from)?,
+            to: end_minutes_to_time(entry.to)?,
+            need_activate: entry.need_activate != 0,
+            value: entry.value,
+        })).collect::<Result<Vec<_>, ScheduleError>>()?;
+        Ok(Self { default_value, entries })
+    }
+
+    /// Checks that every entry ends after it starts and that entries of the same mode don't overlap.
+    pub fn validate(&self) -> Result<(), ScheduleError> {
+        let mut spans: Vec<(usize, i32, u32, u32)> = Vec::with_capacity(self.entries.len());
+        for (idx, entry) in self.entries.iter().enumerate() {
+            let (from, to) = (entry.start_minutes(), entry.end_minutes());
+            if to <= from {
+                return Err(ScheduleError::EmptyEntry(idx));
+            }
+            spans.push((idx, entry.mode, from, to));
+        }
+        spans.sort_by_key(|&(_, mode, from, _)| (mode, from));
+        for pair in spans.windows(2) {
+            let ((prev_idx, prev_mode, _, prev_to), (idx, mode, from, _)) = (pair[0], pair[1]);
+            if prev_mode == mode && from < prev_to {
+                return Err(ScheduleError::Overlap(prev_idx.min(idx), prev_idx.max(idx)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the raw daytimer entries of the schedule.
+    pub fn to_entries(&self) -> Vec<LoxoneDaytimerEntry> {
+        self.entries.iter().map(|entry| LoxoneDaytimerEntry {
+            mode: entry.mode,
+            from: entry.start_minutes() as i32,
+            to: entry.end_minutes() as i32,
+            need_activate: entry.need_activate as i32,
+            value: entry.value,
+        }).collect()
+    }
+
+    fn set_cmd(&self, analog: bool) -> Result<LoxoneMutation, ScheduleError> {
+        self.validate()?;
+        let entries: Vec<String> = self.to_entries().iter().map(|entry| match analog {
+            true => format!("{};{};{};{};{}", entry.mode, entry.from, entry.to, entry.need_activate, entry.value),
+            false => format!("{};{};{};{}", entry.mode, entry.from, entry.to, entry.need_activate),
+        }).collect();
+        match entries.is_empty() {
+            true => Ok(String::from("set/0")),
+            false => Ok(format!("set/{}/{}", entries.len(), entries.join("/"))),
+        }
+    }
+}
+
+impl LoxoneScheduleEntry {
+    fn start_minutes(&self) -> u32 {
+        self.from.hour() * 60 + self.from.minute()
+    }
+
+    fn end_minutes(&self) -> u32 {
+        match self.to.hour() * 60 + self.to.minute() {
+            0 => 24 * 60,
+            minutes => minutes,
+        }
+    }
+}
+
+impl Daytimer {
+    pub fn set(&self, schedule: &LoxoneSchedule) -> Result<LoxoneMutation, ScheduleError> { schedule.set_cmd(self.details.analog) }
+    pub fn default_value(value: f64) -> LoxoneMutation { format!("default/{}", value) }
+}
+
+impl IRCV2Daytimer {
+    pub fn set(&self, schedule: &LoxoneSchedule) -> Result<LoxoneMutation, ScheduleError> { schedule.set_cmd(self.details.analog) }
+}
+
+fn minutes_to_time(minutes: i32) -> Result<NaiveTime, ScheduleError> {
+    let time = match minutes {
+        0..=1439 => NaiveTime::from_hms_opt(minutes as u32 / 60, minutes as u32 % 60, 0),
+        _ => None,
+    };
+    time.ok_or(ScheduleError::InvalidTime(minutes))
+}
+
+/// Like [`minutes_to_time`], but also accepts 1440 as the end of the day.
+fn end_minutes_to_time(minutes: i32) -> Result<NaiveTime, ScheduleError> {
+    match minutes {
+        1440 => NaiveTime::from_hms_opt(0, 0, 0).ok_or(ScheduleError::InvalidTime(minutes)),
+        _ => minutes_to_time(minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(mode: i32, from: i32, to: i32, need_activate: i32, value: f64) -> LoxoneDaytimerEntry {
+        LoxoneDaytimerEntry { mode, from, to, need_activate, value }
+    }
+
+    fn schedule(entries: &[LoxoneDaytimerEntry]) -> LoxoneSchedule {
+        LoxoneSchedule::from_entries(entries, 18.0, &HashMap::new()).unwrap()
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn converts_daytimer_entries() {
+        let operating_modes = vec![(0, String::from("Default")), (2, String::from("Weekday"))].into_iter().collect();
+        let entries = [raw(2, 360, 1440, 1, 21.5), raw(100, 0, 480, 0, 19.0)];
+        let schedule = LoxoneSchedule::from_entries(&entries, 18.0, &operating_modes).unwrap();
+        assert_eq!(schedule.entries[0], LoxoneScheduleEntry {
+            mode: 2,
+            mode_name: Some(String::from("Weekday")),
+            from: time(6, 0),
+            to: time(0, 0),
+            need_activate: true,
+            value: 21.5,
+        });
+        assert_eq!((schedule.entries[1].mode_name.as_deref(), schedule.entries[1].from, schedule.entries[1].to), (None, time(0, 0), time(8, 0)));
+        assert_eq!(schedule.to_entries(), entries);
+    }
+
+    #[test]
+    fn handles_end_of_day() {
+        assert_eq!(LoxoneSchedule::from_entries(&[raw(0, 1440, 1440, 0, 1.0)], 0.0, &HashMap::new()), Err(ScheduleError::InvalidTime(1440)));
+        assert_eq!(LoxoneSchedule::from_entries(&[raw(0, 0, 1441, 0, 1.0)], 0.0, &HashMap::new()), Err(ScheduleError::InvalidTime(1441)));
+        assert_eq!(LoxoneSchedule::from_entries(&[raw(0, -1, 60, 0, 1.0)], 0.0, &HashMap::new()), Err(ScheduleError::InvalidTime(-1)));
+        // A `to` of 00:00 ends at midnight, whether it was received as 0 or 1440.
+        let schedule = schedule(&[raw(0, 0, 0, 0, 1.0), raw(1, 1380, 1440, 0, 1.0)]);
+        assert_eq!(schedule.validate(), Ok(()));
+        assert_eq!(schedule.to_entries(), [raw(0, 0, 1440, 0, 1.0), raw(1, 1380, 1440, 0, 1.0)]);
+    }
+
+    #[test]
+    fn validates_entries() {
+        assert_eq!(schedule(&[raw(0, 480, 480, 0, 1.0)]).validate(), Err(ScheduleError::EmptyEntry(0)));
+        assert_eq!(schedule(&[raw(0, 0, 60, 0, 1.0), raw(0, 600, 540, 0, 1.0)]).validate(), Err(ScheduleError::EmptyEntry(1)));
+        // Entries of the same mode may touch but not overlap, regardless of their order.
+        assert_eq!(schedule(&[raw(0, 600, 720, 0, 1.0), raw(1, 0, 1440, 0, 1.0), raw(0, 480, 601, 0, 1.0)]).validate(), Err(ScheduleError::Overlap(0, 2)));
+        assert_eq!(schedule(&[raw(0, 600, 720, 0, 1.0), raw(1, 0, 1440, 0, 1.0), raw(0, 480, 600, 0, 1.0)]).validate(), Ok(()));
+        assert_eq!(schedule(&[raw(0, 0, 1440, 0, 1.0), raw(0, 1380, 1440, 0, 1.0)]).validate(), Err(ScheduleError::Overlap(0, 1)));
+    }
+
+    #[test]
+    fn builds_set_commands() {
+        let schedule = schedule(&[raw(0, 360, 1440, 1, 21.5), raw(3, 0, 480, 0, 19.0)]);
+        assert_eq!(schedule.set_cmd(true), Ok(String::from("set/2/0;360;1440;1;21.5/3;0;480;0;19")));
+        assert_eq!(schedule.set_cmd(false), Ok(String::from("set/2/0;360;1440;1/3;0;480;0")));
+        let empty = LoxoneSchedule { default_value: 0.0, entries: Vec::new() };
+        assert_eq!((empty.set_cmd(true), empty.set_cmd(false)), (Ok(String::from("set/0")), Ok(String::from("set/0"))));
+        let overlapping = self::schedule(&[raw(0, 0, 600, 0, 1.0), raw(0, 540, 720, 0, 1.0)]);
+        assert_eq!(overlapping.set_cmd(true), Err(ScheduleError::Overlap(0, 1)));
+    }
+}
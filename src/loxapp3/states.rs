@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+use thiserror::Error;
+
+use crate::loxapp3::color::{LoxoneColor, ParseColorError};
+use crate::loxapp3::message_center::{LoxoneMessageCenter, LoxoneMessageEntry};
+
+/// Typed value of a text state whose payload is encoded by the owning control.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoxoneTextState {
+    MoodList(Vec<LoxoneMood>),
+    MoodIds(Vec<i32>),
+    OverrideEntries(Vec<LoxoneOverrideEntry>),
+    Color(LoxoneColor),
+    Colors(Vec<LoxoneColor>),
+    ColorSequence(LoxoneColorSequence),
+    ColorSequences(Vec<LoxoneColorSequence>),
+    Notifications(Vec<LoxoneNotification>),
+    UserSettings(serde_json::Map<String, serde_json::Value>),
+    MessageCenter(LoxoneMessageCenter),
+    Text(String),
+}
+
+/// Mood of a light controller.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoxoneMood {
+    pub id: i32,
+    pub name: String,
+    #[serde(default, rename = "static")]
+    pub is_static: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Active override of an intelligent room controller.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneOverrideEntry {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub reason: Option<i32>,
+    #[serde(default)]
+    pub is_timer: bool,
+    pub source: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Sequence of colors cycled through by a color picker.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoxoneColorSequence {
+    pub name: Option<String>,
+    pub colors: Vec<LoxoneColor>,
+    pub duration: Option<f64>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Notification pushed by the Miniserver.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoxoneNotification {
+    pub uid: Option<String>,
+    pub ts: Option<i64>,
+    pub r#type: Option<i32>,
+    pub title: Option<String>,
+    pub message: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Error, Debug)]
+pub enum TextStateError {
+    #[error("invalid json payload")]
+    JsonDeserialize(#[from] serde_json::Error),
+    #[error("invalid color payload")]
+    Color(#[from] ParseColorError),
+}
+
+/// Decodes the text of the `state_name` state of a control with the given controller type.
+///
+/// States without a known encoding are returned as [`LoxoneTextState::Text`].
+pub fn decode_text_state(type_name: &str, state_name: &str, text: &str) -> Result<LoxoneTextState, TextStateError> {
+    match (type_name, state_name) {
+        ("LightControllerV2", "moodList") => Ok(LoxoneTextState::MoodList(serde_json::from_str(text)?)),
+        ("LightControllerV2", "activeMoods") | ("LightControllerV2", "favoriteMoods") | ("LightControllerV2", "additionalMoods") =>
+            Ok(LoxoneTextState::MoodIds(serde_json::from_str(text)?)),
+        ("IRoomControllerV2", "overrideEntries") => Ok(LoxoneTextState::OverrideEntries(serde_json::from_str(text)?)),
+        ("ColorPicker", "color") | ("ColorPickerV2", "color") => Ok(LoxoneTextState::Color(text.parse()?)),
+        ("ColorPicker", "favorites") => Ok(LoxoneTextState::Colors(serde_json::from_str(text)?)),
+        ("ColorPickerV2", "sequence") => Ok(LoxoneTextState::ColorSequence(serde_json::from_str(text)?)),
+        _ => Ok(LoxoneTextState::Text(text.to_owned())),
+    }
+}
+
+/// Decodes the text of the `state_name` global state.
+///
+/// States without a known encoding are returned as [`LoxoneTextState::Text`].
+pub fn decode_global_text_state(state_name: &str, text: &str) -> Result<LoxoneTextState, TextStateError> {
+    match state_name {
+        "favColors" => Ok(LoxoneTextState::Colors(serde_json::from_str(text)?)),
+        "favColorSequences" => Ok(LoxoneTextState::ColorSequences(serde_json::from_str(text)?)),
+        "notifications" => match serde_json::from_str(text)? {
+            serde_json::Value::Array(notifications) => Ok(LoxoneTextState::Notifications(serde_json::from_value(serde_json::Value::Array(notifications))?)),
+            notification => Ok(LoxoneTextState::Notifications(vec![serde_json::from_value(notification)?])),
+        },
+        "userSettings" => Ok(LoxoneTextState::UserSettings(serde_json::from_str(text)?)),
+        _ => Ok(LoxoneTextState::Text(text.to_owned())),
+    }
+}
+
+/// Decodes the text of a message center state.
+///
+/// Both the `{"entries": [...]}` object and a bare array of entries are accepted.
+pub fn decode_message_center_text_state(text: &str) -> Result<LoxoneTextState, TextStateError> {
+    match serde_json::from_str(text)? {
+        serde_json::Value::Array(entries) => Ok(LoxoneTextState::MessageCenter(LoxoneMessageCenter {
+            entries: serde_json::from_value::<Vec<LoxoneMessageEntry>>(serde_json::Value::Array(entries))?,
+            other: serde_json::Map::new(),
+        })),
+        message_center => Ok(LoxoneTextState::MessageCenter(serde_json::from_value(message_center)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loxapp3::{LoxoneApp3, LoxoneState};
+
+    const LOXAPP3: &str = include_str!("../../tests/fixtures/LoxAPP3.json");
+
+    fn hsv(hue: f64, saturation: f64, value: f64) -> LoxoneColor {
+        LoxoneColor::Hsv { hue, saturation, value }
+    }
+
+    #[test]
+    fn decodes_color_states() {
+        for type_name in &["ColorPicker", "ColorPickerV2"] {
+            assert_eq!(decode_text_state(type_name, "color", "hsv(120,50,75)").unwrap(), LoxoneTextState::Color(hsv(120.0, 50.0, 75.0)));
+            assert_eq!(decode_text_state(type_name, "color", "temp(80,2700)").unwrap(), LoxoneTextState::Color(LoxoneColor::Temp { brightness: 80.0, temperature: 2700.0 }));
+            assert!(matches!(decode_text_state(type_name, "color", "rgb(1,2,3)"), Err(TextStateError::Color(_))));
+        }
+        assert_eq!(
+            decode_text_state("ColorPicker", "favorites", r#"["hsv(0,100,100)", "temp(100,6500)"]"#).unwrap(),
+            LoxoneTextState::Colors(vec![hsv(0.0, 100.0, 100.0), LoxoneColor::Temp { brightness: 100.0, temperature: 6500.0 }]),
+        );
+        match decode_text_state("ColorPickerV2", "sequence", r#"{"colors": ["hsv(0,100,100)", "hsv(240,100,100)"], "duration": 60, "loop": true}"#).unwrap() {
+            LoxoneTextState::ColorSequence(sequence) => {
+                assert_eq!((sequence.name, sequence.colors, sequence.duration), (None, vec![hsv(0.0, 100.0, 100.0), hsv(240.0, 100.0, 100.0)], Some(60.0)));
+                assert_eq!(sequence.other["loop"], true);
+            },
+            state => panic!("unexpected {:?}", state),
+        }
+        assert!(matches!(decode_text_state("ColorPicker", "favorites", r#"["hsv(0,100)"]"#), Err(TextStateError::JsonDeserialize(_))));
+    }
+
+    #[test]
+    fn decodes_json_states() {
+        match decode_text_state("LightControllerV2", "moodList", r#"[{"id": 778, "name": "Off", "static": true}, {"id": 1, "name": "Evening", "t": 2}]"#).unwrap() {
+            LoxoneTextState::MoodList(moods) => {
+                assert_eq!(moods.iter().map(|mood| (mood.id, mood.name.as_str(), mood.is_static)).collect::<Vec<_>>(), [(778, "Off", true), (1, "Evening", false)]);
+                assert_eq!(moods[1].other["t"], 2);
+            },
+            state => panic!("unexpected {:?}", state),
+        }
+        for state_name in &["activeMoods", "favoriteMoods", "additionalMoods"] {
+            assert_eq!(decode_text_state("LightControllerV2", state_name, "[1,778]").unwrap(), LoxoneTextState::MoodIds(vec![1, 778]));
+        }
+        match decode_text_state("IRoomControllerV2", "overrideEntries", r#"[{"start": 558316800, "end": 558320400, "reason": 3, "isTimer": true, "source": "App"}]"#).unwrap() {
+            LoxoneTextState::OverrideEntries(entries) => assert_eq!(
+                (entries[0].start, entries[0].end, entries[0].reason, entries[0].is_timer, entries[0].source.as_deref()),
+                (Some(558_316_800), Some(558_320_400), Some(3), true, Some("App")),
+            ),
+            state => panic!("unexpected {:?}", state),
+        }
+        assert!(matches!(decode_text_state("LightControllerV2", "moodList", "[{\"id\": 1"), Err(TextStateError::JsonDeserialize(_))));
+    }
+
+    #[test]
+    fn keeps_unknown_states_as_text() {
+        assert_eq!(decode_text_state("Switch", "active", "[1]").unwrap(), LoxoneTextState::Text(String::from("[1]")));
+        assert_eq!(decode_text_state("LightController", "moodList", "hsv(0,0,0)").unwrap(), LoxoneTextState::Text(String::from("hsv(0,0,0)")));
+        assert_eq!(decode_global_text_state("liveSearch", "{}").unwrap(), LoxoneTextState::Text(String::from("{}")));
+    }
+
+    #[test]
+    fn decodes_global_states() {
+        assert_eq!(decode_global_text_state("favColors", r#"["hsv(30,80,100)"]"#).unwrap(), LoxoneTextState::Colors(vec![hsv(30.0, 80.0, 100.0)]));
+        match decode_global_text_state("favColorSequences", r#"[{"name": "Party", "colors": ["hsv(0,100,100)"]}]"#).unwrap() {
+            LoxoneTextState::ColorSequences(sequences) => assert_eq!((sequences[0].name.as_deref(), sequences[0].colors.len()), (Some("Party"), 1)),
+            state => panic!("unexpected {:?}", state),
+        }
+        let notification = r#"{"uid": "a1", "ts": 558316800, "type": 10, "title": "Alarm", "message": "Window open"}"#;
+        for text in &[notification.to_owned(), format!("[{}]", notification)] {
+            match decode_global_text_state("notifications", text).unwrap() {
+                LoxoneTextState::Notifications(notifications) => assert_eq!(
+                    notifications.iter().map(|n| (n.uid.as_deref(), n.ts, n.r#type, n.title.as_deref(), n.message.as_deref())).collect::<Vec<_>>(),
+                    [(Some("a1"), Some(558_316_800), Some(10), Some("Alarm"), Some("Window open"))],
+                ),
+                state => panic!("unexpected {:?}", state),
+            }
+        }
+        match decode_global_text_state("userSettings", r#"{"darkMode": true}"#).unwrap() {
+            LoxoneTextState::UserSettings(settings) => assert_eq!(settings["darkMode"], true),
+            state => panic!("unexpected {:?}", state),
+        }
+        assert!(matches!(decode_global_text_state("userSettings", "[]"), Err(TextStateError::JsonDeserialize(_))));
+    }
+
+    #[test]
+    fn decodes_states_by_owner() {
+        let loxapp3: LoxoneApp3 = serde_json::from_str(LOXAPP3).unwrap();
+        let index = loxapp3.index();
+        let text = |text: &str| LoxoneState::Text(text.to_owned(), String::new());
+        assert_eq!(index.decode_state("2000710a-0b6a-0c6a-ffff504f94a0006a", &text("hsv(10,20,30)")).unwrap(), Some(LoxoneTextState::Color(hsv(10.0, 20.0, 30.0))));
+        assert_eq!(index.decode_state("1003eeeb-0a3b-0c3b-ffff504f94a0003b", &text("[778]")).unwrap(), Some(LoxoneTextState::MoodIds(vec![778])));
+        assert_eq!(index.decode_state("1005221d-0a4d-0c4d-ffff504f94a0004d", &text("[]")).unwrap(), Some(LoxoneTextState::Colors(Vec::new())));
+        assert_eq!(index.decode_state("2000710a-0b6a-0c6a-ffff504f94a0006a", &LoxoneState::Value(1.0)).unwrap(), None);
+        assert_eq!(index.decode_state("00000000-0000-0000-ffff000000000000", &text("[]")).unwrap(), None);
+    }
+}
@@ -0,0 +1,136 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::Cursor;
+
+use thiserror::Error;
+
+use crate::epoch::LoxoneTimestamp;
+use crate::loxapp3::{LoxoneApp3, LoxoneController, LoxoneStatistic, LoxoneUUID};
+
+/// Control recording statistics, as returned by [`LoxoneApp3::statistics`].
+#[derive(Debug, Clone, Copy)]
+pub struct LoxoneStatisticSource<'a> {
+    pub uuid: &'a LoxoneUUID,
+    pub name: &'a str,
+    pub statistic: &'a LoxoneStatistic,
+}
+
+/// Record of a binary statistics file, holding one value per statistic output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoxoneStatisticRecord {
+    pub timestamp: LoxoneTimestamp,
+    pub values: Vec<f64>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum StatisticsError {
+    #[error("statistics data of {0} bytes isn't a multiple of the {1} bytes record size")]
+    InvalidLength(usize, usize),
+}
+
+impl LoxoneApp3 {
+    /// Returns the controls and sub-controls recording statistics.
+    pub fn statistics(&self) -> Vec<LoxoneStatisticSource<'_>> {
+        let mut sources = Vec::new();
+        for control in self.controls.values() {
+            if let Some(statistic) = &control.statistic {
+                sources.push(LoxoneStatisticSource { uuid: &control.uuid_action, name: &control.name, statistic });
+            }
+            collect_sub_control_statistics(&control.controller, &mut sources);
+        }
+        sources
+    }
+}
+
+impl LoxoneStatistic {
+    /// Returns the path of the binary statistics file of the given control and month.
+    pub fn file_path(uuid: &str, year: i32, month: u32) -> String {
+        format!("binstatisticdata/{}/{:04}{:02}", uuid, year, month)
+    }
+
+    /// Decodes a binary statistics file recorded with this configuration.
+    pub fn decode(&self, data: &[u8]) -> Result<Vec<LoxoneStatisticRecord>, StatisticsError> {
+        decode_statistics(data, self.outputs.len())
+    }
+}
+
+/// Decodes a binary statistics file with `outputs` values per record.
+///
+/// Each record consists of the 16 bytes UUID of the control, a 4 bytes timestamp and an 8 bytes value per output.
+pub fn decode_statistics(data: &[u8], outputs: usize) -> Result<Vec<LoxoneStatisticRecord>, StatisticsError> {
+    let record_len = 16 + 4 + 8 * outputs;
+    let chunks = data.chunks_exact(record_len);
+    if !chunks.remainder().is_empty() {
+        return Err(StatisticsError::InvalidLength(data.len(), record_len));
+    }
+    let records = chunks.map(|record| {
+        let mut pack = Cursor::new(&record[16..]);
+        let timestamp = LoxoneTimestamp::from(pack.read_u32::<LittleEndian>().unwrap());
+        let values = (0..outputs).map(|_| pack.read_f64::<LittleEndian>().unwrap()).collect();
+        LoxoneStatisticRecord { timestamp, values }
+    }).collect();
+    Ok(records)
+}
+
+fn collect_sub_control_statistics<'a>(controller: &'a LoxoneController, sources: &mut Vec<LoxoneStatisticSource<'a>>) {
+    for sub_control in controller.sub_controls().into_iter().flat_map(|sub_controls| sub_controls.values()) {
+        if let Some(statistic) = &sub_control.statistic {
+            sources.push(LoxoneStatisticSource { uuid: &sub_control.uuid_action, name: &sub_control.name, statistic });
+        }
+        collect_sub_control_statistics(&sub_control.controller, sources);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loxapp3::LoxoneStatisticFrequency;
+
+    const LOXAPP3: &str = include_str!("../../tests/fixtures/LoxAPP3.json");
+
+    fn record(timestamp: u32, values: &[f64]) -> Vec<u8> {
+        let mut data = b"\x10\x00\x44\x44\x0a\x04\x0c\x04\xff\xff\x50\x4f\x94\xa0\x00\x04".to_vec();
+        data.extend_from_slice(&timestamp.to_le_bytes());
+        for value in values {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn decodes_records() {
+        let data = [record(558_316_800, &[21.5, -3.25]), record(558_317_100, &[22.0, 0.0])].concat();
+        assert_eq!(data.len(), 2 * (16 + 4 + 8 * 2));
+        assert_eq!(decode_statistics(&data, 2), Ok(vec![
+            LoxoneStatisticRecord { timestamp: LoxoneTimestamp(558_316_800), values: vec![21.5, -3.25] },
+            LoxoneStatisticRecord { timestamp: LoxoneTimestamp(558_317_100), values: vec![22.0, 0.0] },
+        ]));
+        assert_eq!(decode_statistics(&[], 2), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn rejects_truncated_records() {
+        let data = [record(558_316_800, &[21.5, -3.25]), record(558_317_100, &[22.0, 0.0])].concat();
+        assert_eq!(decode_statistics(&data[..data.len() - 1], 2), Err(StatisticsError::InvalidLength(71, 36)));
+        assert_eq!(decode_statistics(&data, 3), Err(StatisticsError::InvalidLength(72, 44)));
+    }
+
+    #[test]
+    fn collects_control_and_sub_control_statistics() {
+        let mut json: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+        json["controls"]["10044440-0a40-0c40-ffff504f94a00040"]["subControls"]["10049995-0a45-0c45-ffff504f94a00045"]["statistic"] = serde_json::json!({
+            "frequency": 3,
+            "outputs": [{ "id": 0, "name": "Position", "format": "%.0f%%", "uuid": "10045551-0a41-0c41-ffff504f94a00041", "visuType": 1 }],
+        });
+        let loxapp3: LoxoneApp3 = serde_json::from_value(json).unwrap();
+        let mut sources: Vec<_> = loxapp3.statistics().into_iter().map(|source| (source.uuid.as_str(), source.name, source.statistic.frequency)).collect();
+        sources.sort_unstable_by_key(|source| source.0);
+        assert_eq!(sources, [
+            ("10004444-0a04-0c04-ffff504f94a00004", "Kitchen Socket", LoxoneStatisticFrequency::EveryChange),
+            ("10049995-0a45-0c45-ffff504f94a00045", "Ceiling Light", LoxoneStatisticFrequency::Average5Min),
+        ]);
+
+        let dimmer = loxapp3.statistics().into_iter().find(|source| source.name == "Ceiling Light").unwrap();
+        assert_eq!(dimmer.statistic.decode(&record(558_316_800, &[40.0])).unwrap()[0].values, [40.0]);
+        assert_eq!(LoxoneStatistic::file_path(dimmer.uuid, 2026, 9), "binstatisticdata/10049995-0a45-0c45-ffff504f94a00045/202609");
+    }
+}
@@ -0,0 +1,157 @@
+use chrono::NaiveDateTime;
+use std::convert::TryFrom;
+
+use crate::epoch::LoxoneTimestamp;
+use crate::loxapp3::{LoxoneWeatherEntry, LoxoneWeatherFormat, LoxoneWeatherServer};
+
+/// Weather forecast received from the weather server, linked to its format info from the structure file.
+#[derive(Debug, Clone)]
+pub struct LoxoneWeatherForecast<'a> {
+    pub last_update: NaiveDateTime,
+    pub entries: Vec<LoxoneForecastEntry<'a>>,
+    pub format: Option<&'a LoxoneWeatherFormat>,
+}
+
+/// Hourly forecast entry, timestamps are in Miniserver local time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoxoneForecastEntry<'a> {
+    pub time: NaiveDateTime,
+    pub weather_type: LoxoneWeatherType,
+    /// Localized weather type text, as configured in the structure file.
+    pub weather_text: Option<&'a str>,
+    pub wind_direction: LoxoneWindDirection,
+    /// Wind direction in degrees, 0 meaning north.
+    pub wind_degrees: i32,
+    /// Solar radiation in W/m².
+    pub solar_radiation: i32,
+    /// Relative humidity in percent.
+    pub relative_humidity: i32,
+    /// Temperature in °C.
+    pub temperature: f64,
+    /// Perceived temperature in °C.
+    pub perceived_temperature: f64,
+    /// Dew point in °C.
+    pub dew_point: f64,
+    /// Precipitation in mm.
+    pub precipitation: f64,
+    /// Wind speed in km/h.
+    pub wind_speed: f64,
+    /// Barometric pressure in hPa.
+    pub barometric_pressure: f64,
+}
+
+/// Weather type, codes follow the meteoblue pictocodes used by the Loxone weather service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoxoneWeatherType {
+    Clear,
+    MostlyClear,
+    PartlyCloudy,
+    ThunderstormClouds,
+    Hazy,
+    Fog,
+    MostlyCloudy,
+    Overcast,
+    Rain,
+    Snow,
+    HeavyRain,
+    HeavySnow,
+    Thunderstorm,
+    LightThunderstorm,
+    SnowStorm,
+    HeavyThunderstorm,
+    Showers,
+    SnowShowers,
+    LightRain,
+    LightSnow,
+    Sleet,
+    Unknown(i32),
+}
+
+/// Compass direction the wind is blowing from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoxoneWindDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl<'a> LoxoneWeatherForecast<'a> {
+    /// Creates a forecast from weather state entries, resolving weather texts through the `weather_server` of the structure file.
+    pub fn new(entries: &[LoxoneWeatherEntry], last_update: u32, server: Option<&'a LoxoneWeatherServer>) -> Self {
+        let entries = entries.iter().map(|entry| LoxoneForecastEntry {
+            time: LoxoneTimestamp::from(entry.timestamp).naive_local(),
+            weather_type: LoxoneWeatherType::from(entry.weather_type),
+            weather_text: server.and_then(|server| {
+                let code = u16::try_from(entry.weather_type).ok()?;
+                server.weather_type_texts.get(&code).map(String::as_str)
+            }),
+            wind_direction: LoxoneWindDirection::from_degrees(entry.wind_direction),
+            wind_degrees: entry.wind_direction,
+            solar_radiation: entry.solar_radiation,
+            relative_humidity: entry.relative_humidity,
+            temperature: entry.temperature,
+            perceived_temperature: entry.perceived_temperature,
+            dew_point: entry.dew_point,
+            precipitation: entry.precipitation,
+            wind_speed: entry.wind_speed,
+            barometric_pressure: entry.barometric_pressure,
+        }).collect();
+        Self { last_update: LoxoneTimestamp::from(last_update).naive_local(), entries, format: server.map(|server| &server.format) }
+    }
+
+    /// Returns the entry covering the given time, if any.
+    pub fn at(&self, time: NaiveDateTime) -> Option<&LoxoneForecastEntry<'a>> {
+        self.entries.iter().take_while(|entry| entry.time <= time).last()
+    }
+}
+
+impl From<i32> for LoxoneWeatherType {
+    fn from(code: i32) -> Self {
+        match code {
+            1..=3 => Self::Clear,
+            4..=6 => Self::MostlyClear,
+            7..=9 => Self::PartlyCloudy,
+            10..=12 => Self::ThunderstormClouds,
+            13..=15 => Self::Hazy,
+            16..=18 => Self::Fog,
+            19..=21 => Self::MostlyCloudy,
+            22 => Self::Overcast,
+            23 => Self::Rain,
+            24 => Self::Snow,
+            25 => Self::HeavyRain,
+            26 => Self::HeavySnow,
+            27 => Self::Thunderstorm,
+            28 => Self::LightThunderstorm,
+            29 => Self::SnowStorm,
+            30 => Self::HeavyThunderstorm,
+            31 => Self::Showers,
+            32 => Self::SnowShowers,
+            33 => Self::LightRain,
+            34 => Self::LightSnow,
+            35 => Self::Sleet,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+impl LoxoneWindDirection {
+    /// Returns the nearest compass direction for the given degrees.
+    pub fn from_degrees(degrees: i32) -> Self {
+        match (degrees.rem_euclid(360) * 2 + 45) / 90 % 8 {
+            0 => Self::North,
+            1 => Self::NorthEast,
+            2 => Self::East,
+            3 => Self::SouthEast,
+            4 => Self::South,
+            5 => Self::SouthWest,
+            6 => Self::West,
+            _ => Self::NorthWest,
+        }
+    }
+}
+
@@ -0,0 +1,328 @@
+//! Local Miniserver speaking the Loxone WebSocket protocol, for integration tests.
+
+use crypto::buffer::{self, BufferResult, ReadBuffer, WriteBuffer};
+use crypto::{aes, blockmodes};
+use futures_util::{SinkExt, StreamExt};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use rsa::{PaddingScheme, PublicKeyParts, RSAPrivateKey};
+use simple_asn1::{ASN1Block, BigInt, BigUint, OID};
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+
+use crate::epoch::LoxoneTimestamp;
+use crate::loxapp3::{LoxoneState, LoxoneUUID};
+use crate::ws::{encode_event_tables, encode_file_msg, encode_keepalive_msg, encode_out_of_service_msg, encode_text_msg, hash_pwd, hash_token};
+
+const SALT: &str = "4d6f636b";
+const TOKEN_LIFESPAN: i64 = 30 * 24 * 3600;
+
+/// Local Miniserver for integration tests.
+///
+/// Performs the key exchange with a generated RSA key, authenticates the configured user with `getkey2`,
+/// `getjwt` and `authwithtoken`, serves the given structure file and records the `jdev/sps/io` commands it receives.
+/// State changes set with [`MockMiniserver::set_state`] are pushed to every client with status updates enabled.
+pub struct MockMiniserver {
+    addr: SocketAddr,
+    cert: String,
+    shared: Arc<Shared>,
+    rx_cmds: mpsc::UnboundedReceiver<(LoxoneUUID, String)>,
+}
+
+struct Shared {
+    private_key: RSAPrivateKey,
+    user: String,
+    password: String,
+    token: String,
+    loxapp3: serde_json::Value,
+    states: Mutex<HashMap<LoxoneUUID, LoxoneState>>,
+    files: Mutex<HashMap<String, Vec<u8>>>,
+    clients: Mutex<Vec<mpsc::UnboundedSender<Push>>>,
+    tx_cmds: mpsc::UnboundedSender<(LoxoneUUID, String)>,
+}
+
+enum Push {
+    Frames(Vec<tungstenite::Message>),
+    OutOfService,
+}
+
+enum Input {
+    Frame(Option<Result<tungstenite::Message, tungstenite::Error>>),
+    Push(Option<Push>),
+}
+
+struct Connection {
+    key: Vec<u8>,
+    cipher: Option<([u8; 32], [u8; 16])>,
+    status_update: bool,
+}
+
+impl MockMiniserver {
+    /// Starts a Miniserver listening on a random local port, serving the given structure file.
+    pub async fn start(loxapp3: serde_json::Value, user: &str, password: &str) -> io::Result<Self> {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let private_key = RSAPrivateKey::new(&mut OsRng, 1024).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err.to_string()))?;
+        let cert = encode_cert(&private_key)?;
+        let (tx_cmds, rx_cmds) = mpsc::unbounded_channel();
+        let shared = Arc::new(Shared {
+            private_key,
+            user: user.to_owned(),
+            password: password.to_owned(),
+            token: encode_token(user),
+            loxapp3,
+            states: Mutex::new(HashMap::new()),
+            files: Mutex::new(HashMap::new()),
+            clients: Mutex::new(Vec::new()),
+            tx_cmds,
+        });
+        let accept_shared = shared.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, accept_shared.clone()));
+            }
+        });
+        Ok(Self { addr, cert, shared, rx_cmds })
+    }
+
+    /// Returns the WebSocket url to connect to.
+    pub fn url(&self) -> http::uri::Uri {
+        format!("ws://{}/ws/rfc6455", self.addr).parse().unwrap()
+    }
+
+    /// Returns the PEM encoded certificate of the Miniserver public key, for [`crate::WebSocket::key_exchange`].
+    pub fn cert(&self) -> &str {
+        &self.cert
+    }
+
+    /// Sets the state of the given UUID, pushing it to the clients with status updates enabled.
+    pub fn set_state(&self, uuid: &str, state: LoxoneState) {
+        let frames = encode_event_tables(vec![(uuid.to_owned(), state.clone())], false);
+        self.shared.states.lock().unwrap().insert(uuid.to_owned(), state);
+        self.shared.push(|| Push::Frames(frames.clone()));
+    }
+
+    /// Serves the given file, e.g. an icon or a statistics file.
+    pub fn add_file(&self, path: &str, data: Vec<u8>) {
+        self.shared.files.lock().unwrap().insert(path.trim_start_matches('/').to_owned(), data);
+    }
+
+    /// Returns the next `jdev/sps/io` command received by the Miniserver, as control UUID and command.
+    pub async fn next_io_cmd(&mut self) -> Option<(LoxoneUUID, String)> {
+        self.rx_cmds.recv().await
+    }
+
+    /// Sends the out-of-service indicator to all clients and closes their connections.
+    pub fn go_out_of_service(&self) {
+        self.shared.push(|| Push::OutOfService);
+    }
+}
+
+impl Shared {
+    fn push<F: Fn() -> Push>(&self, push: F) {
+        self.clients.lock().unwrap().retain(|tx| tx.send(push()).is_ok());
+    }
+}
+
+impl Connection {
+    fn new() -> Self {
+        let mut key = vec![0; 20];
+        OsRng.fill_bytes(&mut key);
+        Self { key, cipher: None, status_update: false }
+    }
+
+    fn handle(&mut self, shared: &Shared, cmd: &str) -> Vec<tungstenite::Message> {
+        if cmd == "keepalive" {
+            return vec![encode_keepalive_msg()];
+        }
+        if let Some(session_key) = cmd.strip_prefix("jdev/sys/keyexchange/") {
+            return match self.key_exchange(shared, session_key) {
+                Some(()) => {
+                    let mut remote_key = vec![0; 16];
+                    OsRng.fill_bytes(&mut remote_key);
+                    reply(cmd, 200, serde_json::Value::from(base64::encode(&remote_key)))
+                },
+                None => reply(cmd, 400, serde_json::Value::Null),
+            };
+        }
+        if let Some(encrypted) = cmd.strip_prefix("jdev/sys/enc/") {
+            return match self.decrypt(encrypted) {
+                Some(cmd) => self.handle_plain(shared, &cmd),
+                None => reply(cmd, 400, serde_json::Value::Null),
+            };
+        }
+        self.handle_plain(shared, cmd)
+    }
+
+    fn handle_plain(&mut self, shared: &Shared, cmd: &str) -> Vec<tungstenite::Message> {
+        let segments: Vec<&str> = cmd.split('/').collect();
+        match segments.as_slice() {
+            ["jdev", "sys", "getkey"] => reply(cmd, 200, serde_json::Value::from(hex::encode(&self.key))),
+            ["jdev", "sys", "getkey2", _user] => reply(cmd, 200, serde_json::json!({
+                "key": hex::encode(&self.key),
+                "salt": SALT,
+                "hashAlg": "SHA256",
+            })),
+            ["jdev", "sys", "getjwt", hash, user, _permission, _uuid, _info] => {
+                let expected = hash_pwd(&shared.user, &shared.password, &self.key, SALT, "SHA256");
+                match *user == shared.user && *hash == hex::encode(expected.expose()) {
+                    true => reply(cmd, 200, serde_json::json!({
+                        "token": shared.token,
+                        "key": hex::encode(&self.key),
+                        "validUntil": LoxoneTimestamp::now(chrono::FixedOffset::east_opt(0).unwrap()).0 + TOKEN_LIFESPAN,
+                        "tokenRights": 4,
+                        "unsecurePass": false,
+                    })),
+                    false => reply(cmd, 401, serde_json::Value::Null),
+                }
+            },
+            ["authwithtoken", hash, user] => {
+                let expected = hash_token(&shared.token, &self.key, "SHA1");
+                match *user == shared.user && *hash == hex::encode(expected.expose()) {
+                    true => reply(cmd, 200, serde_json::json!({
+                        "validUntil": LoxoneTimestamp::now(chrono::FixedOffset::east_opt(0).unwrap()).0 + TOKEN_LIFESPAN,
+                        "tokenRights": 4,
+                    })),
+                    false => reply(cmd, 401, serde_json::Value::Null),
+                }
+            },
+            ["data", "LoxAPP3.json"] => {
+                encode_file_msg(tungstenite::Message::Text(shared.loxapp3.to_string()))
+            },
+            ["jdev", "sps", "LoxAPPversion3"] => {
+                let version = shared.loxapp3["lastModified"].as_str().unwrap_or_default().to_owned();
+                reply(cmd, 200, serde_json::Value::from(version))
+            },
+            ["jdev", "cfg", "timezoneoffset"] => reply(cmd, 200, serde_json::Value::from("0")),
+            ["jdev", "sps", "enablebinstatusupdate"] => {
+                self.status_update = true;
+                let states = shared.states.lock().unwrap().clone();
+                let mut frames = reply(cmd, 200, serde_json::Value::from("1"));
+                frames.extend(encode_event_tables(states, true));
+                frames
+            },
+            ["jdev", "sps", "io", uuid, ..] => {
+                let io_cmd = cmd.splitn(5, '/').nth(4).unwrap_or_default().to_owned();
+                let _ = shared.tx_cmds.send((uuid.to_string(), io_cmd));
+                reply(cmd, 200, serde_json::Value::from("1"))
+            },
+            _ => match shared.files.lock().unwrap().get(cmd) {
+                Some(data) => encode_file_msg(tungstenite::Message::Binary(data.clone())),
+                None => reply(cmd, 404, serde_json::Value::Null),
+            },
+        }
+    }
+
+    fn key_exchange(&mut self, shared: &Shared, session_key: &str) -> Option<()> {
+        let session_key = base64::decode_config(session_key, base64::STANDARD_NO_PAD).ok()?;
+        let session_key = shared.private_key.decrypt(PaddingScheme::PKCS1v15Encrypt, &session_key).ok()?;
+        let session_key = String::from_utf8(session_key).ok()?;
+        let mut parts = session_key.split(':');
+        let (key, iv) = (hex::decode(parts.next()?).ok()?, hex::decode(parts.next()?).ok()?);
+        let (mut aes_key, mut aes_iv) = ([0; 32], [0; 16]);
+        if key.len() != aes_key.len() || iv.len() != aes_iv.len() {
+            return None;
+        }
+        aes_key.copy_from_slice(&key);
+        aes_iv.copy_from_slice(&iv);
+        self.cipher = Some((aes_key, aes_iv));
+        Some(())
+    }
+
+    fn decrypt(&self, encrypted: &str) -> Option<String> {
+        let (key, iv) = self.cipher.as_ref()?;
+        let encoded: String = url::form_urlencoded::parse(encrypted.as_bytes()).next().map(|(encoded, _)| encoded.into_owned())?;
+        let cipher = base64::decode_config(&encoded, base64::STANDARD_NO_PAD).ok()?;
+
+        let mut decryptor = aes::cbc_decryptor(aes::KeySize::KeySize256, key, iv, blockmodes::PkcsPadding);
+        let mut plain = Vec::<u8>::new();
+        let mut read_buffer = buffer::RefReadBuffer::new(&cipher);
+        let mut buffer = [0; 4096];
+        let mut write_buffer = buffer::RefWriteBuffer::new(&mut buffer);
+        loop {
+            let result = decryptor.decrypt(&mut read_buffer, &mut write_buffer, true).ok()?;
+            plain.extend(write_buffer.take_read_buffer().take_remaining().iter());
+            if let BufferResult::BufferUnderflow = result {
+                break;
+            }
+        }
+
+        let plain = String::from_utf8(plain).ok()?;
+        let mut parts = plain.trim_end_matches('\0').splitn(3, '/');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("salt"), Some(_salt), Some(cmd)) => Some(cmd.to_owned()),
+            _ => None,
+        }
+    }
+}
+
+async fn serve(stream: TcpStream, shared: Arc<Shared>) {
+    let ws_stream = match tokio_tungstenite::accept_hdr_async(stream, accept_protocol).await {
+        Ok(ws_stream) => ws_stream,
+        Err(_) => return,
+    };
+    let (mut sink, mut stream) = ws_stream.split();
+    let (tx_push, mut rx_push) = mpsc::unbounded_channel();
+    shared.clients.lock().unwrap().push(tx_push);
+    let mut conn = Connection::new();
+    loop {
+        let input = tokio::select! {
+            frame = stream.next() => Input::Frame(frame),
+            push = rx_push.recv() => Input::Push(push),
+        };
+        let frames = match input {
+            Input::Frame(Some(Ok(tungstenite::Message::Text(cmd)))) => conn.handle(&shared, &cmd),
+            Input::Frame(Some(Ok(tungstenite::Message::Close(_)))) | Input::Frame(Some(Err(_))) | Input::Frame(None) => break,
+            Input::Frame(Some(Ok(_))) => continue,
+            Input::Push(Some(Push::Frames(frames))) if conn.status_update => frames,
+            Input::Push(Some(Push::Frames(_))) => continue,
+            Input::Push(Some(Push::OutOfService)) => {
+                let _ = sink.send(encode_out_of_service_msg()).await;
+                let _ = sink.close().await;
+                break;
+            },
+            Input::Push(None) => break,
+        };
+        for frame in frames {
+            if sink.send(frame).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn accept_protocol(_request: &Request, mut response: Response) -> Result<Response, ErrorResponse> {
+    response.headers_mut().insert("Sec-WebSocket-Protocol", http::HeaderValue::from_static("remotecontrol"));
+    Ok(response)
+}
+
+fn reply(control: &str, code: u16, value: serde_json::Value) -> Vec<tungstenite::Message> {
+    let code = code.to_string();
+    encode_text_msg(serde_json::json!({ "LL": { "control": control, "value": value, "Code": code, "code": code } }).to_string())
+}
+
+/// Encodes the public key as PEM `SubjectPublicKeyInfo`, the form served by the Miniserver `getPublicKey` endpoint.
+fn encode_cert(private_key: &RSAPrivateKey) -> io::Result<String> {
+    let to_asn1_int = |val: &rsa::BigUint| ASN1Block::Integer(0, BigInt::from(BigUint::from_bytes_be(&val.to_bytes_be())));
+    let encode_err = |err: simple_asn1::ASN1EncodeErr| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", err));
+    let pkcs1 = simple_asn1::to_der(&ASN1Block::Sequence(0, vec![to_asn1_int(private_key.n()), to_asn1_int(private_key.e())])).map_err(encode_err)?;
+    let rsa_encryption = OID::new([1u32, 2, 840, 113_549, 1, 1, 1].iter().map(|&val| BigUint::from(val)).collect());
+    let spki = simple_asn1::to_der(&ASN1Block::Sequence(0, vec![
+        ASN1Block::Sequence(0, vec![ASN1Block::ObjectIdentifier(0, rsa_encryption), ASN1Block::Null(0)]),
+        ASN1Block::BitString(0, pkcs1.len() * 8, pkcs1),
+    ])).map_err(encode_err)?;
+    Ok(pem::encode(&pem::Pem { tag: String::from("PUBLIC KEY"), contents: spki }))
+}
+
+fn encode_token(user: &str) -> String {
+    let header = base64::encode(serde_json::json!({ "alg": "none", "typ": "JWT" }).to_string());
+    let payload = base64::encode(serde_json::json!({ "user": user }).to_string());
+    format!("{}.{}.", header, payload)
+}
@@ -0,0 +1,283 @@
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::time::Instant;
+
+use thiserror::Error;
+
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite;
+
+use crate::loxapp3::{LoxoneState, LoxoneUUID};
+use crate::ws::{parse_msg_next, redact_cmd, Message, ParseMessageError};
+
+/// Records the frames of a WebSocket session to a file, one JSON encoded [`RecordedFrame`] per line.
+///
+/// Encrypted commands, session keys, one-time keys and tokens are redacted before being written.
+#[derive(Debug, Clone)]
+pub struct SessionRecorder {
+    tx: mpsc::UnboundedSender<RecordedFrame>,
+    started: Instant,
+}
+
+/// Session recorded by a [`SessionRecorder`], replayed offline through the message parser.
+#[derive(Debug, Clone)]
+pub struct SessionReplay {
+    frames: Vec<RecordedFrame>,
+}
+
+/// Raw WebSocket frame of a recorded session.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedFrame {
+    pub direction: FrameDirection,
+    /// Milliseconds elapsed since the recorder was created.
+    pub elapsed_ms: u64,
+    #[serde(flatten)]
+    pub payload: RecordedPayload,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrameDirection {
+    /// Frame sent by the Miniserver.
+    In,
+    /// Command sent to the Miniserver.
+    Out,
+}
+
+/// Payload of a recorded frame, binary frames are stored base64 encoded.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "lowercase")]
+pub enum RecordedPayload {
+    Text(String),
+    Binary(#[serde(with = "base64_bytes")] Vec<u8>),
+}
+
+/// Message decoded from a replayed session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayEvent {
+    Text(String),
+    File(Vec<u8>),
+    States(HashMap<LoxoneUUID, LoxoneState>),
+    OutOfService,
+    KeepAlive,
+}
+
+#[derive(Error, Debug)]
+pub enum ReplayError {
+    #[error("io error")]
+    Io(#[from] io::Error),
+    #[error("invalid recorded frame on line {0}")]
+    JsonDeserialize(usize, #[source] serde_json::Error),
+    #[error("invalid recorded message")]
+    InvalidMessage(#[source] ParseMessageError),
+}
+
+impl SessionRecorder {
+    /// Creates the file at `path` and spawns the task writing the recorded frames to it.
+    pub async fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut file = tokio::fs::File::create(path).await?;
+        let (tx, mut rx) = mpsc::unbounded_channel::<RecordedFrame>();
+        tokio::spawn(async move {
+            while let Some(frame) = rx.recv().await {
+                let mut line = serde_json::to_vec(&frame).unwrap();
+                line.push(b'\n');
+                if let Err(err) = file.write_all(&line).await {
+                    tracing::warn!(%err, "failed to record frame");
+                    return;
+                }
+            }
+            let _ = file.flush().await;
+        });
+        Ok(Self { tx, started: Instant::now() })
+    }
+
+    pub(crate) fn record_incoming(&self, frame: &tungstenite::Message) {
+        let payload = match frame {
+            tungstenite::Message::Text(text) => RecordedPayload::Text(redact_reply(text)),
+            tungstenite::Message::Binary(data) => RecordedPayload::Binary(data.clone()),
+            _ => return,
+        };
+        self.record(FrameDirection::In, payload);
+    }
+
+    pub(crate) fn record_outgoing(&self, cmd: &str) {
+        self.record(FrameDirection::Out, RecordedPayload::Text(redact_cmd(cmd).into_owned()));
+    }
+
+    fn record(&self, direction: FrameDirection, payload: RecordedPayload) {
+        let elapsed_ms = self.started.elapsed().as_millis() as u64;
+        let _ = self.tx.send(RecordedFrame { direction, elapsed_ms, payload });
+    }
+}
+
+impl SessionReplay {
+    /// Reads a session recorded by a [`SessionRecorder`].
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self, ReplayError> {
+        let recording = tokio::fs::read_to_string(path).await?;
+        let frames = recording.lines().enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| serde_json::from_str(line).map_err(|err| ReplayError::JsonDeserialize(idx + 1, err)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { frames })
+    }
+
+    /// Creates a replay of the given frames, e.g. built with the `encode_*` functions.
+    pub fn from_frames(frames: Vec<RecordedFrame>) -> Self {
+        Self { frames }
+    }
+
+    /// Returns the recorded frames, in both directions.
+    pub fn frames(&self) -> &[RecordedFrame] {
+        &self.frames
+    }
+
+    /// Returns the messages sent by the Miniserver, decoded by the same parser as a live session.
+    ///
+    /// Invalid messages are yielded as errors and the replay continues with the following frames.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ReplayEvent, ReplayError>> {
+        let frames = self.frames.into_iter()
+            .filter(|frame| frame.direction == FrameDirection::In)
+            .map(|frame| Ok(tungstenite::Message::from(frame.payload)));
+        stream::unfold(stream::iter(frames), |mut frames| async move {
+            match parse_msg_next(&mut frames).await {
+                Ok(msg) => Some((Ok(ReplayEvent::from(msg)), frames)),
+                Err(ParseMessageError::Transport(tungstenite::Error::ConnectionClosed)) => None,
+                Err(err) => Some((Err(ReplayError::InvalidMessage(err)), frames)),
+            }
+        })
+    }
+
+    /// Returns the state updates of the session, as the stream returned by [`crate::WebSocket::enable_status_update`].
+    ///
+    /// Like a live session, invalid messages are logged and skipped.
+    pub fn into_state_stream(self) -> impl Stream<Item = (LoxoneUUID, LoxoneState)> {
+        self.into_stream().flat_map(|event| match event {
+            Ok(ReplayEvent::States(states)) => stream::iter(states),
+            Ok(_) => stream::iter(HashMap::new()),
+            Err(err) => {
+                tracing::error!(%err, "skipping invalid recorded message");
+                stream::iter(HashMap::new())
+            },
+        })
+    }
+}
+
+impl From<RecordedPayload> for tungstenite::Message {
+    fn from(payload: RecordedPayload) -> Self {
+        match payload {
+            RecordedPayload::Text(text) => tungstenite::Message::Text(text),
+            RecordedPayload::Binary(data) => tungstenite::Message::Binary(data),
+        }
+    }
+}
+
+impl From<Message> for ReplayEvent {
+    fn from(msg: Message) -> Self {
+        match msg {
+            Message::Text(text) => Self::Text(text),
+            Message::BinaryText(text) => Self::File(text.into_bytes()),
+            Message::BinaryFile(data) => Self::File(data),
+            Message::EventTable(event_table) => Self::States(event_table.into()),
+            Message::OutOfServiceIndicator => Self::OutOfService,
+            Message::KeepAlive => Self::KeepAlive,
+        }
+    }
+}
+
+/// Redacts the echoed encrypted command and the keys and tokens of a text reply.
+fn redact_reply(text: &str) -> String {
+    let mut reply: serde_json::Value = match serde_json::from_str(text) {
+        Ok(reply) => reply,
+        Err(_) => return text.to_owned(),
+    };
+    let control = reply["LL"]["control"].as_str().unwrap_or_default().to_owned();
+    if let Some(ll) = reply.get_mut("LL").and_then(serde_json::Value::as_object_mut) {
+        if ll.contains_key("control") {
+            ll.insert(String::from("control"), serde_json::Value::from(redact_cmd(&control).into_owned()));
+        }
+        match ll.get_mut("value") {
+            Some(serde_json::Value::Object(value)) => {
+                for field in &["key", "token"] {
+                    if let Some(secret) = value.get_mut(*field) {
+                        *secret = serde_json::Value::from("***");
+                    }
+                }
+            },
+            Some(value) if control.contains("/keyexchange/") || control.ends_with("/getkey") => *value = serde_json::Value::from("***"),
+            _ => (),
+        }
+    }
+    reply.to_string()
+}
+
+mod base64_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::encode(data))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        base64::decode(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws::{encode_event_tables, encode_text_msg};
+
+    fn reply(control: &str, value: serde_json::Value) -> String {
+        serde_json::json!({ "LL": { "control": control, "value": value, "code": "200" } }).to_string()
+    }
+
+    #[tokio::test]
+    async fn records_redacted_session_and_replays_it() {
+        let path = std::env::temp_dir().join(format!("loxone-session-{}.jsonl", std::process::id()));
+        let recorder = SessionRecorder::create(&path).await.unwrap();
+        recorder.record_outgoing("jdev/sys/keyexchange/c2Vzc2lvbmtleQ");
+        encode_text_msg(reply("jdev/sys/keyexchange/c2Vzc2lvbmtleQ", serde_json::Value::from("cmVtb3Rla2V5"))).iter().for_each(|frame| recorder.record_incoming(frame));
+        recorder.record_outgoing("jdev/sys/enc/ZW5jcnlwdGVkand0");
+        let jwt = reply("jdev/sys/enc/ZW5jcnlwdGVkand0", serde_json::json!({
+            "token": "eyJ0eXAi.eyJ1c2VyIjoiYWRtaW4ifQ.c2ln",
+            "key": "4B45590A",
+            "validUntil": 547394710,
+        }));
+        encode_text_msg(jwt).iter().for_each(|frame| recorder.record_incoming(frame));
+        let states = encode_event_tables(vec![(String::from("10003333-0a03-0c03-ffff504f94a00003"), LoxoneState::Value(1.0))], false);
+        states.iter().for_each(|frame| recorder.record_incoming(frame));
+        recorder.record_incoming(&tungstenite::Message::Binary(vec![0x03, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]));
+        encode_text_msg(String::from("{}")).iter().for_each(|frame| recorder.record_incoming(frame));
+        drop(recorder);
+
+        let mut recording = String::new();
+        for _ in 0..100 {
+            recording = tokio::fs::read_to_string(&path).await.unwrap();
+            if recording.lines().count() == 11 {
+                break;
+            }
+            tokio::time::delay_for(std::time::Duration::from_millis(10)).await;
+        }
+        for secret in &["c2Vzc2lvbmtleQ", "cmVtb3Rla2V5", "ZW5jcnlwdGVkand0", "eyJ0eXAi", "4B45590A"] {
+            assert!(!recording.contains(secret), "{} not redacted", secret);
+        }
+
+        let replay = SessionReplay::open(&path).await.unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(replay.frames().len(), 11);
+        let events: Vec<Result<ReplayEvent, ReplayError>> = replay.clone().into_stream().collect().await;
+        assert_eq!(events.len(), 5);
+        assert!(matches!(&events[1], Ok(ReplayEvent::Text(text)) if text.contains("547394710") && text.contains("***")));
+        assert!(matches!(&events[2], Ok(ReplayEvent::States(states)) if states.len() == 1));
+        assert!(matches!(&events[3], Err(ReplayError::InvalidMessage(ParseMessageError::InvalidMessage(_)))));
+        assert!(matches!(&events[4], Ok(ReplayEvent::Text(text)) if text == "{}"));
+
+        let states: Vec<(LoxoneUUID, LoxoneState)> = replay.into_state_stream().collect().await;
+        assert_eq!(states, vec![(String::from("10003333-0a03-0c03-ffff504f94a00003"), LoxoneState::Value(1.0))]);
+    }
+}
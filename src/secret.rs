@@ -0,0 +1,92 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use zeroize::Zeroize;
+
+/// Sensitive value such as a key, token or password.
+///
+/// The value is zeroized on drop and printed as `***` in `Debug`.
+pub struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the secret value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the secret value mutably.
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl Secret<String> {
+    /// Creates an empty secret string that can hold `capacity` bytes without reallocating.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self(String::with_capacity(capacity))
+    }
+
+    /// Appends `s`, moving the value into a larger buffer and zeroizing the old one if it does not fit.
+    pub(crate) fn push_str(&mut self, s: &str) {
+        if self.0.len() + s.len() > self.0.capacity() {
+            let mut grown = Self::with_capacity(2 * (self.0.len() + s.len()));
+            grown.0.push_str(&self.0);
+            std::mem::swap(self, &mut grown);
+        }
+        self.0.push_str(s);
+    }
+
+    /// Appends the bytes as hex digits.
+    pub(crate) fn push_hex(&mut self, bytes: &[u8], uppercase: bool) {
+        let digits: &[u8; 16] = if uppercase { b"0123456789ABCDEF" } else { b"0123456789abcdef" };
+        let mut pair = [0; 2];
+        for byte in bytes {
+            pair[0] = digits[(byte >> 4) as usize];
+            pair[1] = digits[(byte & 0xf) as usize];
+            self.push_str(std::str::from_utf8(&pair).expect("hex digits are ASCII"));
+        }
+        pair.zeroize();
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Zeroize + Clone> Clone for Secret<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+/// Serializes the exposed value, e.g. to persist a token.
+impl<T: Zeroize + Serialize> Serialize for Secret<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Zeroize + Deserialize<'de>> Deserialize<'de> for Secret<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self)
+    }
+}
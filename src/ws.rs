@@ -1,4 +1,6 @@
-use byteorder::{LittleEndian, ReadBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use chrono::FixedOffset;
 
 use crypto::digest::Digest;
 use crypto::mac::Mac;
@@ -18,29 +20,115 @@ use rand::rngs::OsRng;
 
 use rsa::{PublicKey, RSAPublicKey};
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
 
 use thiserror::Error;
 
+use tracing::Instrument;
+
 use tokio::{net::TcpStream, stream::Stream, sync::mpsc};
 use tokio_tungstenite::{connect_async, tungstenite, WebSocketStream};
 
-use crate::loxapp3::{LoxoneMutation, LoxoneUUID, LoxoneState, LoxoneDaytimerEntry, LoxoneWeatherEntry};
+use crate::epoch::LoxoneTimestamp;
+use crate::loxapp3::{LoxoneMutation, LoxoneUUID, LoxoneState, LoxoneStatistic, LoxoneDaytimerEntry, LoxoneWeatherEntry};
+use crate::loxapp3::modes::LoxoneCalendarEntry;
+use crate::loxapp3::statistics::{LoxoneStatisticRecord, StatisticsError};
+use crate::record::SessionRecorder;
+use crate::secret::Secret;
+
+type WsSink = SplitSink<WebSocketStream<TcpStream>, tungstenite::Message>;
+type WsStream = Pin<Box<dyn Stream<Item = Result<tungstenite::Message, tungstenite::Error>> + Send>>;
 
 /// WebSocket client for communicating with the Miniserver.
 pub struct WebSocket {
-    session: Option<Session>,
-    rx: mpsc::UnboundedReceiver<Message>,
-    sink: SplitSink<WebSocketStream<TcpStream>, tungstenite::Message>,
+    shared: Arc<Shared>,
+    rx: mpsc::UnboundedReceiver<Result<Message, tungstenite::Error>>,
+    rx_conn: Option<ConnectionEventReceiver>,
+}
+
+/// Connection state shared with the receive loop, which replaces the sink and resets the session when reconnecting.
+struct Shared {
+    url: http::uri::Uri,
+    sink: tokio::sync::Mutex<WsSink>,
+    session: Mutex<Option<Session>>,
+    reconnect_policy: Mutex<Option<ReconnectPolicy>>,
+    out_of_service: AtomicBool,
+    tx_conn: mpsc::UnboundedSender<ConnectionEvent>,
+    /// `modifications` global state, read for each state update so it may be set before or after enabling status updates.
+    modifications: Mutex<Option<LoxoneUUID>>,
+    recorder: Option<SessionRecorder>,
+}
+
+/// Automatic reconnection behavior of the receive loop, see [`WebSocket::set_reconnect_policy`].
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Delay before the first attempt when the Miniserver announced it goes out of service, e.g. for a reboot or an update.
+    pub out_of_service_delay: Duration,
+    /// Delay before the first attempt otherwise, doubled after each failed attempt.
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub max_attempts: Option<u32>,
+}
+
+/// Lifecycle event of the connection to the Miniserver.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionEvent {
+    Connected,
+    Authenticated,
+    KeepAlive,
+    OutOfService,
+    Disconnected(String),
+    Reconnecting(u32),
+    /// A message could not be parsed and was skipped.
+    InvalidMessage(String),
+    /// The `modifications` global state changed, see [`StructureCache::is_modification`](crate::StructureCache::is_modification).
+    StructureChanged,
+}
+
+/// Unbounded receiver for receiving connection lifecycle events.
+pub struct ConnectionEventReceiver {
+    rx: mpsc::UnboundedReceiver<ConnectionEvent>
 }
 
 struct Session {
-    rsa_key: [u8; 32],
-    rsa_iv: [u8; 16],
+    rsa_key: Secret<[u8; 32]>,
+    rsa_iv: Secret<[u8; 16]>,
     salt: [u8; 2],
-    session_key: Vec<u8>,
+    session_key: Secret<Vec<u8>>,
+}
+
+/// File downloaded from the Miniserver.
+#[derive(Debug, Clone)]
+pub struct LoxoneFile {
+    pub data: Vec<u8>,
+    /// MIME type detected from the file content.
+    pub content_type: &'static str,
+}
+
+/// JSON Web Token returned by [`WebSocket::get_jwt`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoxoneJwt {
+    pub token: Secret<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Secret<String>>,
+    pub valid_until: LoxoneTimestamp,
+    #[serde(default)]
+    pub token_rights: u32,
+    #[serde(default)]
+    pub unsecure_pass: bool,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Unbounded receiver for receiving state update events.
@@ -48,6 +136,7 @@ pub struct EventReceiver {
     rx: mpsc::UnboundedReceiver<EventTable>
 }
 
+#[derive(Debug)]
 enum MessageType {
     Text = 0,
     BinaryFile,
@@ -60,7 +149,7 @@ enum MessageType {
 }
 
 #[derive(Debug)]
-enum Message {
+pub(crate) enum Message {
     Text(String),
     BinaryText(String),
     BinaryFile(Vec<u8>),
@@ -70,22 +159,37 @@ enum Message {
 }
 
 #[derive(Debug)]
-struct ValueEvent(LoxoneUUID, f64);
+pub(crate) struct ValueEvent(LoxoneUUID, f64);
 #[derive(Debug)]
-struct TextEvent(LoxoneUUID, LoxoneUUID, String);
+pub(crate) struct TextEvent(LoxoneUUID, LoxoneUUID, String);
 #[derive(Debug)]
-struct DaytimerEvent(LoxoneUUID, f64, Vec<LoxoneDaytimerEntry>);
+pub(crate) struct DaytimerEvent(LoxoneUUID, f64, Vec<LoxoneDaytimerEntry>);
 #[derive(Debug)]
-struct WeatherEvent(LoxoneUUID, u32, Vec<LoxoneWeatherEntry>);
+pub(crate) struct WeatherEvent(LoxoneUUID, u32, Vec<LoxoneWeatherEntry>);
 
 #[derive(Debug)]
-enum EventTable {
+pub(crate) enum EventTable {
     ValueEvents(Vec<ValueEvent>),
     TextEvents(Vec<TextEvent>),
     DaytimerEvents(Vec<DaytimerEvent>),
     WeatherEvents(Vec<WeatherEvent>),
 }
 
+/// Error returned for requests pending while the Miniserver goes out of service.
+///
+/// Transported as [`io::Error`] within [`tungstenite::Error::Io`], see [`OutOfServiceError::matches`].
+#[derive(Error, Debug)]
+#[error("miniserver is out of service")]
+pub struct OutOfServiceError;
+
+#[derive(Error, Debug)]
+pub enum ParseMessageError {
+    #[error("transport error")]
+    Transport(#[from] tungstenite::Error),
+    #[error("{0}")]
+    InvalidMessage(String),
+}
+
 #[derive(Error, Debug)]
 pub enum X509CertError {
     #[error("pem error")]
@@ -128,6 +232,16 @@ pub enum RequestError {
     JsonMissingField(&'static str),
     #[error("invalid reply status code")]
     InvalidStatusCode(String),
+    #[error("unexpected reply value")]
+    InvalidValue(String),
+}
+
+#[derive(Error, Debug)]
+pub enum StatisticsRequestError {
+    #[error("file request error")]
+    FileRequest(#[from] RequestError),
+    #[error("invalid statistics data")]
+    Decode(#[from] StatisticsError),
 }
 
 #[derive(Error, Debug)]
@@ -183,12 +297,70 @@ pub enum LoxAPP3RequestError {
 impl WebSocket {
     /// Connects to the given WebSocket url.
     pub async fn connect(url: http::uri::Uri) -> Result<(Self, tungstenite::handshake::client::Response, EventReceiver, impl future::Future<Output = ()>), tungstenite::Error> {
+        Self::connect_session(url, None).await
+    }
+
+    /// Connects to the given WebSocket url, recording the frames of the session with the given recorder.
+    pub async fn connect_with_recorder(url: http::uri::Uri, recorder: SessionRecorder) -> Result<(Self, tungstenite::handshake::client::Response, EventReceiver, impl future::Future<Output = ()>), tungstenite::Error> {
+        Self::connect_session(url, Some(recorder)).await
+    }
+
+    async fn connect_session(url: http::uri::Uri, recorder: Option<SessionRecorder>) -> Result<(Self, tungstenite::handshake::client::Response, EventReceiver, impl future::Future<Output = ()>), tungstenite::Error> {
+        let (tx_conn, rx_conn) = mpsc::unbounded_channel();
+        let (sink, stream, resp) = Self::open(&url, recorder.clone()).await?;
+        let _ = tx_conn.send(ConnectionEvent::Connected);
+        let shared = Arc::new(Shared {
+            url,
+            sink: tokio::sync::Mutex::new(sink),
+            session: Mutex::new(None),
+            reconnect_policy: Mutex::new(None),
+            out_of_service: AtomicBool::new(false),
+            tx_conn,
+            modifications: Mutex::new(None),
+            recorder,
+        });
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (tx_events, rx_events) = mpsc::unbounded_channel();
+        let recv_loop = Self::recv_loop(Arc::downgrade(&shared), tx, tx_events, stream);
+        let ws = Self{shared, rx, rx_conn: Some(ConnectionEventReceiver::new(rx_conn))};
+        Ok((ws, resp, EventReceiver::new(rx_events), recv_loop))
+    }
+
+    /// Enables reconnecting automatically with the given policy when the connection is lost.
+    ///
+    /// After reconnecting the session is reset: key exchange and authentication have to be performed again
+    /// once [`ConnectionEvent::Connected`] is received, followed by [`WebSocket::reenable_status_update`].
+    pub fn set_reconnect_policy(&mut self, policy: Option<ReconnectPolicy>) {
+        *self.shared.reconnect_policy.lock().unwrap() = policy;
+    }
+
+    /// Returns the receiver for connection lifecycle events, only once.
+    pub fn connection_events(&mut self) -> Option<ConnectionEventReceiver> {
+        self.rx_conn.take()
+    }
+
+    /// Sets the `modifications` global state, reported as [`ConnectionEvent::StructureChanged`] by the status update stream.
+    ///
+    /// Takes effect on a stream returned before by [`WebSocket::enable_status_update`] as well.
+    pub(crate) fn set_modifications_state(&mut self, uuid: LoxoneUUID) {
+        *self.shared.modifications.lock().unwrap() = Some(uuid);
+    }
+
+    /// Returns `true` if the Miniserver announced it goes out of service.
+    pub fn is_out_of_service(&self) -> bool {
+        self.shared.out_of_service.load(Ordering::SeqCst)
+    }
+
+    async fn open(url: &http::uri::Uri, recorder: Option<SessionRecorder>) -> Result<(WsSink, WsStream, tungstenite::handshake::client::Response), tungstenite::Error> {
         let request = Request::builder().uri(url).header("Sec-WebSocket-protocol", "remotecontrol").body(())?;
         let (ws_stream, resp) = connect_async(request).await?;
         let (sink, stream) = ws_stream.split();
-        let (tx, rx) = mpsc::unbounded_channel();
-        let (tx_events, rx_events) = mpsc::unbounded_channel();
-        Ok((Self{sink, rx, session: None}, resp, EventReceiver::new(rx_events), Self::recv_loop(tx, tx_events, stream)))
+        let stream = stream.inspect(move |frame| {
+            if let (Some(recorder), Ok(frame)) = (&recorder, frame) {
+                recorder.record_incoming(frame);
+            }
+        });
+        Ok((sink, Box::pin(stream), resp))
     }
 
     /// Exchanges session key.
@@ -200,7 +372,8 @@ impl WebSocket {
                 match reply_json["LL"]["Code"].as_str() {
                     Some("200") => {
                         let remote_key = base64::decode(reply_json["LL"]["value"].as_str().ok_or(KeyExchangeError::JsonMissingField("LL.value"))?)?;
-                        self.session = Some(session);
+                        tracing::debug!("session key exchanged");
+                        *self.shared.session.lock().unwrap() = Some(session);
                         Ok(remote_key)
                     },
                     Some(status_code) => Err(KeyExchangeError::InvalidStatusCode(status_code.to_owned())),
@@ -212,16 +385,30 @@ impl WebSocket {
     }
 
     /// Authenticates with the given token.
-    pub async fn authenticate(&mut self, token: &str) -> Result<serde_json::Map<String, serde_json::Value>, AuthenticationError> {
-        let key = &self.get_key().await?;
-        let hash = hash_token(token, &hex::decode(&key)?, "SHA1");
-        let payload: serde_json::Map<String, serde_json::Value> = serde_json::from_slice(&base64::decode(token.split('.').nth(1).ok_or(AuthenticationError::JwtBadFormat)?)?)?;
-        match self.send_recv_enc(&format!("authwithtoken/{}/{}", hex::encode(hash), payload["user"].as_str().ok_or(RequestError::JsonMissingField("LL.value.user"))?)).await? {
+    pub async fn authenticate(&mut self, token: &Secret<String>) -> Result<serde_json::Map<String, serde_json::Value>, AuthenticationError> {
+        let key = Secret::new(self.get_key().await?);
+        let key = Secret::new(hex::decode(key.expose())?);
+        let hash = hash_token(token.expose(), key.expose(), "SHA1");
+        let payload: serde_json::Map<String, serde_json::Value> = serde_json::from_slice(&base64::decode(token.expose().split('.').nth(1).ok_or(AuthenticationError::JwtBadFormat)?)?)?;
+        let user = payload["user"].as_str().ok_or(RequestError::JsonMissingField("LL.value.user"))?;
+        let mut cmd = Secret::with_capacity(14 + 2 * hash.expose().len() + 1 + user.len());
+        cmd.push_str("authwithtoken/");
+        cmd.push_hex(hash.expose(), false);
+        cmd.push_str("/");
+        cmd.push_str(user);
+        match self.send_recv_enc(&cmd).await? {
             Message::Text(reply) => {
                 let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
                 match reply_json["LL"]["code"].as_str() {
-                    Some("200") => Ok(reply_json["LL"]["value"].as_object().ok_or(AuthenticationError::JsonMissingField("LL.value"))?.to_owned()),
-                    Some(status_code) => Err(AuthenticationError::InvalidStatusCode(status_code.to_owned())),
+                    Some("200") => {
+                        tracing::debug!(user, "authenticated with token");
+                        let _ = self.shared.tx_conn.send(ConnectionEvent::Authenticated);
+                        Ok(reply_json["LL"]["value"].as_object().ok_or(AuthenticationError::JsonMissingField("LL.value"))?.to_owned())
+                    },
+                    Some(status_code) => {
+                        tracing::warn!(status_code, "token authentication failed");
+                        Err(AuthenticationError::InvalidStatusCode(status_code.to_owned()))
+                    },
                     None => Err(AuthenticationError::JsonMissingField("LL.code"))
                 }
             },
@@ -258,22 +445,38 @@ impl WebSocket {
     }
 
     /// Returns the JSON Web Token for the given authentication credentials.
-    pub async fn get_jwt(&mut self, user: &str, password: &str, permission: u8, uuid: &str, info: &str) -> Result<serde_json::Map<String, serde_json::Value>, JwtRequestError> {
+    pub async fn get_jwt(&mut self, user: &str, password: &Secret<String>, permission: u8, uuid: &str, info: &str) -> Result<LoxoneJwt, JwtRequestError> {
         let auth = self.get_key_salt(user).await?;
+        let key = Secret::new(hex::decode(auth["key"].as_str().ok_or(RequestError::JsonMissingField("LL.value.key"))?)?);
         let hash = hash_pwd(
             user,
-            password,
-            &hex::decode(auth["key"].as_str().ok_or(RequestError::JsonMissingField("LL.value.key"))?)?,
+            password.expose(),
+            key.expose(),
             auth["salt"].as_str().ok_or(RequestError::JsonMissingField("LL.value.salt"))?,
             auth["hashAlg"].as_str().ok_or(RequestError::JsonMissingField("LL.value.hashAlg"))?
         );
 
-        match self.send_recv_enc(&format!("jdev/sys/getjwt/{}/{}/{}/{}/{}", hex::encode(hash), user, permission, uuid, info)).await? {
+        let params = format!("/{}/{}/{}/{}", user, permission, uuid, info);
+        let mut cmd = Secret::with_capacity(16 + 2 * hash.expose().len() + params.len());
+        cmd.push_str("jdev/sys/getjwt/");
+        cmd.push_hex(hash.expose(), false);
+        cmd.push_str(&params);
+        match self.send_recv_enc(&cmd).await? {
             Message::Text(reply) => {
-                let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply.replace("\r", ""))?;
+                let mut reply = Secret::new(reply);
+                reply.expose_mut().retain(|c| c != '\r');
+                let mut reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(reply.expose())?;
                 match reply_json["LL"]["code"].as_str() {
-                    Some("200") => Ok(reply_json["LL"]["value"].as_object().ok_or(JwtRequestError::JsonMissingField("LL.value"))?.to_owned()),
-                    Some(status_code) => Err(JwtRequestError::InvalidStatusCode(status_code.to_owned())),
+                    Some("200") => {
+                        tracing::debug!(user, permission, "authenticated with user credentials");
+                        let _ = self.shared.tx_conn.send(ConnectionEvent::Authenticated);
+                        let value = reply_json.get_mut("LL").and_then(|ll| ll.get_mut("value")).ok_or(JwtRequestError::JsonMissingField("LL.value"))?;
+                        Ok(serde_json::from_value(value.take())?)
+                    },
+                    Some(status_code) => {
+                        tracing::warn!(user, status_code, "token request failed");
+                        Err(JwtRequestError::InvalidStatusCode(status_code.to_owned()))
+                    },
                     None => Err(JwtRequestError::JsonMissingField("LL.code"))
                 }
             },
@@ -292,12 +495,33 @@ impl WebSocket {
         }
     }
 
+    /// Returns a file stored on the Miniserver, e.g. an icon referenced by the structure file.
+    pub async fn get_file(&mut self, path: &str) -> Result<LoxoneFile, RequestError> {
+        match self.send_recv(path.trim_start_matches('/')).await? {
+            Message::BinaryFile(data) => Ok(LoxoneFile::from(data)),
+            Message::BinaryText(text) => Ok(LoxoneFile::from(text.into_bytes())),
+            Message::Text(reply) => {
+                let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
+                match reply_json["LL"]["Code"].as_str().or_else(|| reply_json["LL"]["code"].as_str()) {
+                    Some(status_code) => Err(RequestError::InvalidStatusCode(status_code.to_owned())),
+                    None => Err(RequestError::JsonMissingField("LL.Code"))
+                }
+            },
+            _reply => Err(RequestError::InvalidMessageType)
+        }
+    }
+
+    /// Returns the statistics recorded by a control during the given month.
+    pub async fn get_statistics(&mut self, uuid: &str, statistic: &LoxoneStatistic, year: i32, month: u32) -> Result<Vec<LoxoneStatisticRecord>, StatisticsRequestError> {
+        let file = self.get_file(&LoxoneStatistic::file_path(uuid, year, month)).await?;
+        Ok(statistic.decode(&file.data)?)
+    }
+
     /// Returns the LoxAPP3.json update timestamp.
     pub async fn get_loxapp3_timestamp(&mut self) -> Result<String, RequestError> {
         match self.send_recv("jdev/sps/LoxAPPversion3").await? {
             Message::Text(reply) => {
                 let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
-                assert_eq!(reply_json["LL"]["Code"].as_str(), Some("200"));
                 match reply_json["LL"]["Code"].as_str() {
                     Some("200") => Ok(reply_json["LL"]["value"].as_str().ok_or(RequestError::JsonMissingField("LL.value"))?.to_owned()),
                     Some(status_code) => Err(RequestError::InvalidStatusCode(status_code.to_owned())),
@@ -308,17 +532,52 @@ impl WebSocket {
         }
     }
 
+    /// Returns the Miniserver time zone offset, used to convert Loxone timestamps.
+    pub async fn get_timezone_offset(&mut self) -> Result<FixedOffset, RequestError> {
+        match self.send_recv("jdev/cfg/timezoneoffset").await? {
+            Message::Text(reply) => {
+                let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
+                match reply_json["LL"]["Code"].as_str() {
+                    Some("200") => {
+                        let value = reply_json["LL"]["value"].as_str().ok_or(RequestError::JsonMissingField("LL.value"))?;
+                        value.parse().ok().and_then(FixedOffset::east_opt).ok_or(RequestError::JsonMissingField("LL.value"))
+                    },
+                    Some(status_code) => Err(RequestError::InvalidStatusCode(status_code.to_owned())),
+                    None => Err(RequestError::JsonMissingField("LL.Code"))
+                }
+            },
+            _reply => Err(RequestError::InvalidMessageType)
+        }
+    }
+
     /// Enables status updates.
+    ///
+    /// Changes of the `modifications` global state are reported as [`ConnectionEvent::StructureChanged`] once
+    /// [`StructureCache::load`](crate::StructureCache::load) ran, whether before or after enabling status updates.
     pub async fn enable_status_update(&mut self, mut rx: EventReceiver) -> Result<(HashMap<LoxoneUUID, LoxoneState>, impl Stream<Item=(LoxoneUUID, LoxoneState)>), RequestError> {
+        self.reenable_status_update().await?;
+        let initial_state = rx.rx.by_ref().take(4).map(|event_table| event_table.into()).concat().await;
+        let shared = Arc::downgrade(&self.shared);
+        let stream = rx.rx.flat_map(|event_table|stream::iter::<HashMap<LoxoneUUID, LoxoneState>>(event_table.into())).inspect(move |(uuid, _)| {
+            if let Some(shared) = shared.upgrade() {
+                if shared.modifications.lock().unwrap().as_ref() == Some(uuid) {
+                    let _ = shared.tx_conn.send(ConnectionEvent::StructureChanged);
+                }
+            }
+        });
+        Ok((initial_state, stream))
+    }
+
+    /// Enables status updates again after reconnecting, the states are sent through the stream returned by [`WebSocket::enable_status_update`].
+    pub async fn reenable_status_update(&mut self) -> Result<(), RequestError> {
         match self.send_recv("jdev/sps/enablebinstatusupdate").await? {
             Message::Text(reply) => {
                 let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
                 match reply_json["LL"]["Code"].as_str() {
-                    Some("200") => {
-                        assert_eq!(reply_json["LL"]["value"].as_str().ok_or(RequestError::JsonMissingField("LL.value"))?, "1");
-                        let initial_state = rx.rx.by_ref().take(4).map(|event_table| event_table.into()).concat().await;
-                        let stream = rx.rx.flat_map(|event_table|stream::iter::<HashMap<LoxoneUUID, LoxoneState>>(event_table.into()));
-                        Ok((initial_state, stream))
+                    Some("200") => match reply_json["LL"]["value"].as_str() {
+                        Some("1") => Ok(()),
+                        Some(value) => Err(RequestError::InvalidValue(value.to_owned())),
+                        None => Err(RequestError::JsonMissingField("LL.value")),
                     },
                     Some(status_code) => Err(RequestError::InvalidStatusCode(status_code.to_owned())),
                     None => Err(RequestError::JsonMissingField("LL.Code"))
@@ -334,9 +593,28 @@ impl WebSocket {
             Message::Text(reply) => {
                 let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
                 match reply_json["LL"]["Code"].as_str() {
-                    Some("200") => {
-                        assert_eq!(reply_json["LL"]["value"].as_str().ok_or(RequestError::JsonMissingField("LL.value"))?, "1");
-                        Ok(())
+                    Some("200") => match reply_json["LL"]["value"].as_str() {
+                        Some("1") => Ok(()),
+                        Some(value) => Err(RequestError::InvalidValue(value.to_owned())),
+                        None => Err(RequestError::JsonMissingField("LL.value")),
+                    },
+                    Some(status_code) => Err(RequestError::InvalidStatusCode(status_code.to_owned())),
+                    None => Err(RequestError::JsonMissingField("LL.Code"))
+                }
+            },
+            _reply => Err(RequestError::InvalidMessageType)
+        }
+    }
+
+    /// Returns the operating mode calendar entries.
+    pub async fn get_calendar_entries(&mut self) -> Result<Vec<LoxoneCalendarEntry>, RequestError> {
+        match self.send_recv(&format!("jdev/sps/{}", LoxoneCalendarEntry::get_entries())).await? {
+            Message::Text(reply) => {
+                let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
+                match reply_json["LL"]["Code"].as_str() {
+                    Some("200") => match &reply_json["LL"]["value"] {
+                        serde_json::Value::String(value) => Ok(serde_json::from_str(value)?),
+                        value => Ok(serde_json::from_value(value.to_owned())?),
                     },
                     Some(status_code) => Err(RequestError::InvalidStatusCode(status_code.to_owned())),
                     None => Err(RequestError::JsonMissingField("LL.Code"))
@@ -346,50 +624,178 @@ impl WebSocket {
         }
     }
 
+    /// Sends an operating mode calendar command, see [`LoxoneCalendarEntry`].
+    pub async fn send_calendar_cmd(&mut self, cmd: LoxoneMutation) -> Result<(), RequestError> {
+        match self.send_recv(&format!("jdev/sps/{}", cmd)).await? {
+            Message::Text(reply) => {
+                let reply_json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&reply)?;
+                match reply_json["LL"]["Code"].as_str() {
+                    Some("200") => Ok(()),
+                    Some(status_code) => Err(RequestError::InvalidStatusCode(status_code.to_owned())),
+                    None => Err(RequestError::JsonMissingField("LL.Code"))
+                }
+            },
+            _reply => Err(RequestError::InvalidMessageType)
+        }
+    }
+
     async fn send_recv(&mut self, cmd: &str) -> Result<Message, tungstenite::Error> {
-        self.sink.send(tungstenite::Message::from(cmd)).await?;
-        self.recv().await
+        let span = tracing::debug_span!("send_recv", cmd = %redact_cmd(cmd));
+        async move {
+            if self.is_out_of_service() {
+                return Err(OutOfServiceError.into());
+            }
+            // Drops replies and failures left over from a request interrupted by a disconnect.
+            while self.rx.try_recv().is_ok() {}
+            if let Some(recorder) = &self.shared.recorder {
+                recorder.record_outgoing(cmd);
+            }
+            self.shared.sink.lock().await.send(tungstenite::Message::from(cmd)).await?;
+            self.recv().await
+        }.instrument(span).await
     }
 
-    async fn send_recv_enc(&mut self, cmd: &str) -> Result<Message, tungstenite::Error> {
-        let session = self.session.as_ref().ok_or(tungstenite::Error::from(io::Error::from(io::ErrorKind::PermissionDenied)))?;
-        let encrypted_cmd = encrypt_cmd_ws("enc", &cmd, session).or(Err(tungstenite::Error::from(io::Error::new(io::ErrorKind::InvalidInput, cmd))))?;
-        self.send_recv(&encrypted_cmd).await
+    async fn send_recv_enc(&mut self, cmd: &Secret<String>) -> Result<Message, tungstenite::Error> {
+        let encrypted_cmd = {
+            let session = self.shared.session.lock().unwrap();
+            let session = session.as_ref().ok_or(tungstenite::Error::from(io::Error::from(io::ErrorKind::PermissionDenied)))?;
+            encrypt_cmd_ws("enc", cmd.expose(), session).or(Err(tungstenite::Error::from(io::Error::from(io::ErrorKind::InvalidInput))))?
+        };
+        self.send_recv(encrypted_cmd.expose()).await
     }
 
     async fn recv(&mut self) -> Result<Message, tungstenite::Error> {
-        self.rx.recv().await.ok_or(tungstenite::Error::from(io::Error::from(io::ErrorKind::BrokenPipe)))
+        match self.rx.recv().await {
+            Some(Ok(Message::OutOfServiceIndicator)) => Err(OutOfServiceError.into()),
+            Some(msg) => msg,
+            None => Err(tungstenite::Error::from(io::Error::from(io::ErrorKind::BrokenPipe))),
+        }
     }
 
-    async fn recv_loop<S: StreamExt<Item=Result<tungstenite::Message, tungstenite::Error>> + Unpin>(tx: mpsc::UnboundedSender<Message>, tx_events: mpsc::UnboundedSender<EventTable>, stream: S) {
-        let mut stream = stream.filter_map(|item| future::ready(item.ok()));
-        while let Ok(msg) = parse_msg_next(&mut stream).await {
+    async fn recv_loop(shared: Weak<Shared>, tx: mpsc::UnboundedSender<Result<Message, tungstenite::Error>>, tx_events: mpsc::UnboundedSender<EventTable>, mut stream: WsStream) {
+        loop {
+            let err = match Self::recv_msgs(&shared, &tx, &tx_events, &mut stream).await {
+                Some(err) => err,
+                None => {
+                    tracing::debug!("websocket dropped, stopping receive loop");
+                    return;
+                },
+            };
+            let shared = match shared.upgrade() {
+                Some(shared) => shared,
+                None => return,
+            };
+            match err {
+                tungstenite::Error::ConnectionClosed => tracing::debug!("connection closed"),
+                ref err => tracing::error!(%err, "connection lost"),
+            }
+            let _ = shared.tx_conn.send(ConnectionEvent::Disconnected(err.to_string()));
+            // Fails the pending request, if any.
+            if tx.send(Err(tungstenite::Error::from(io::Error::from(io::ErrorKind::BrokenPipe)))).is_err() {
+                return;
+            }
+            stream = match Self::reconnect(&shared).await {
+                Some(stream) => stream,
+                None => return,
+            };
+        }
+    }
+
+    /// Forwards the messages of the connection until it is closed, returning `None` if the [`WebSocket`] was dropped.
+    async fn recv_msgs(shared: &Weak<Shared>, tx: &mpsc::UnboundedSender<Result<Message, tungstenite::Error>>, tx_events: &mpsc::UnboundedSender<EventTable>, stream: &mut WsStream) -> Option<tungstenite::Error> {
+        loop {
+            let msg = match parse_msg_next(stream).await {
+                Ok(msg) => msg,
+                Err(ParseMessageError::Transport(err)) => return Some(err),
+                Err(ParseMessageError::InvalidMessage(reason)) => {
+                    tracing::error!(%reason, "invalid message");
+                    let _ = shared.upgrade()?.tx_conn.send(ConnectionEvent::InvalidMessage(reason.clone()));
+                    // Fails the pending request, if any.
+                    tx.send(Err(tungstenite::Error::Protocol(Cow::Owned(reason)))).ok()?;
+                    continue;
+                },
+            };
             match msg {
-                Message::KeepAlive => println!("KEEP ALIVE"),
-                Message::OutOfServiceIndicator => eprintln!("OUT OF SERVICE"),
-                Message::EventTable(event_table) => tx_events.send(event_table).unwrap(),
-                _ => tx.send(msg).unwrap()
+                Message::KeepAlive => {
+                    tracing::trace!("keep alive");
+                    let _ = shared.upgrade()?.tx_conn.send(ConnectionEvent::KeepAlive);
+                },
+                Message::OutOfServiceIndicator => {
+                    tracing::warn!("miniserver is going out of service");
+                    let shared = shared.upgrade()?;
+                    shared.out_of_service.store(true, Ordering::SeqCst);
+                    let _ = shared.tx_conn.send(ConnectionEvent::OutOfService);
+                    // Fails the pending request, if any.
+                    tx.send(Ok(msg)).ok()?;
+                },
+                Message::EventTable(event_table) => if tx_events.send(event_table).is_err() {
+                    tracing::trace!("status update stream dropped, discarding event table");
+                },
+                _ => tx.send(Ok(msg)).ok()?,
+            }
+        }
+    }
+
+    /// Reconnects following the reconnect policy, returning `None` if it is disabled or gave up.
+    async fn reconnect(shared: &Shared) -> Option<WsStream> {
+        let policy = shared.reconnect_policy.lock().unwrap().clone()?;
+        let mut delay = match shared.out_of_service.load(Ordering::SeqCst) {
+            true => policy.out_of_service_delay,
+            false => policy.initial_delay,
+        };
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            tracing::info!(attempt, delay_ms = delay.as_millis() as u64, "reconnecting");
+            let _ = shared.tx_conn.send(ConnectionEvent::Reconnecting(attempt));
+            tokio::time::delay_for(delay).await;
+            match Self::open(&shared.url, shared.recorder.clone()).await {
+                Ok((sink, stream, _)) => {
+                    *shared.sink.lock().await = sink;
+                    *shared.session.lock().unwrap() = None;
+                    shared.out_of_service.store(false, Ordering::SeqCst);
+                    let _ = shared.tx_conn.send(ConnectionEvent::Connected);
+                    return Some(stream);
+                },
+                Err(err) if matches!(policy.max_attempts, Some(max_attempts) if attempt >= max_attempts) => {
+                    tracing::warn!(%err, attempt, "giving up reconnecting");
+                    return None;
+                },
+                Err(err) => {
+                    tracing::debug!(%err, attempt, "reconnect attempt failed");
+                    delay = (delay * 2).min(policy.max_delay);
+                },
             }
         }
     }
 }
 
+impl From<Vec<u8>> for LoxoneFile {
+    fn from(data: Vec<u8>) -> Self {
+        let content_type = detect_content_type(&data);
+        Self { data, content_type }
+    }
+}
+
 impl Session {
     fn new(cert: &str) -> Result<Self, X509CertError> {
         let public_key = parse_cert(cert)?;
 
-        let mut rsa_key: [u8; 32] = [0; 32];
-        OsRng.fill_bytes(&mut rsa_key);
+        let mut rsa_key = Secret::new([0; 32]);
+        OsRng.fill_bytes(rsa_key.expose_mut());
 
-        let mut rsa_iv: [u8; 16] = [0; 16];
-        OsRng.fill_bytes(&mut rsa_iv);
+        let mut rsa_iv = Secret::new([0; 16]);
+        OsRng.fill_bytes(rsa_iv.expose_mut());
 
         let mut salt: [u8; 2] = [0; 2];
         OsRng.fill_bytes(&mut salt);
 
         let mut session_key_rng = rand::rngs::OsRng;
-        let session_key_data = format!("{}:{}", hex::encode(rsa_key), hex::encode(rsa_iv));
-        let session_key = public_key.encrypt(&mut session_key_rng, rsa::PaddingScheme::PKCS1v15Encrypt, session_key_data.as_bytes())?;
+        let mut session_key_data = Secret::with_capacity(2 * rsa_key.expose().len() + 1 + 2 * rsa_iv.expose().len());
+        session_key_data.push_hex(rsa_key.expose(), false);
+        session_key_data.push_str(":");
+        session_key_data.push_hex(rsa_iv.expose(), false);
+        let session_key = Secret::new(public_key.encrypt(&mut session_key_rng, rsa::PaddingScheme::PKCS1v15Encrypt, session_key_data.expose().as_bytes())?);
 
         Ok(Self { session_key, rsa_key, rsa_iv, salt })
     }
@@ -397,7 +803,7 @@ impl Session {
 
 impl AsRef<[u8]> for Session {
     fn as_ref(&self) -> &[u8] {
-        &self.session_key
+        self.session_key.expose()
     }
 }
 
@@ -405,6 +811,50 @@ impl EventReceiver {
     fn new(rx: mpsc::UnboundedReceiver<EventTable>) -> Self { Self{ rx } }
 }
 
+impl ConnectionEventReceiver {
+    fn new(rx: mpsc::UnboundedReceiver<ConnectionEvent>) -> Self { Self{ rx } }
+
+    /// Receives the next connection lifecycle event.
+    pub async fn recv(&mut self) -> Option<ConnectionEvent> {
+        self.rx.recv().await
+    }
+}
+
+impl Stream for ConnectionEventReceiver {
+    type Item = ConnectionEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            out_of_service_delay: Duration::from_secs(60),
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_attempts: None,
+        }
+    }
+}
+
+impl OutOfServiceError {
+    /// Returns `true` if the given transport error is an [`OutOfServiceError`].
+    pub fn matches(err: &tungstenite::Error) -> bool {
+        match err {
+            tungstenite::Error::Io(err) => matches!(err.get_ref(), Some(err) if err.is::<OutOfServiceError>()),
+            _ => false,
+        }
+    }
+}
+
+impl From<OutOfServiceError> for tungstenite::Error {
+    fn from(err: OutOfServiceError) -> Self {
+        tungstenite::Error::Io(io::Error::new(io::ErrorKind::ConnectionAborted, err))
+    }
+}
+
 impl TryFrom<u8> for MessageType {
     type Error = io::Error;
 
@@ -423,6 +873,75 @@ impl TryFrom<u8> for MessageType {
     }
 }
 
+impl EventTable {
+    fn msg_type(&self) -> MessageType {
+        match self {
+            Self::ValueEvents(_) => MessageType::ValueEventTable,
+            Self::TextEvents(_) => MessageType::TextEventTable,
+            Self::DaytimerEvents(_) => MessageType::DaytimerEventTable,
+            Self::WeatherEvents(_) => MessageType::WeatherEventTable,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::ValueEvents(events) => events.is_empty(),
+            Self::TextEvents(events) => events.is_empty(),
+            Self::DaytimerEvents(events) => events.is_empty(),
+            Self::WeatherEvents(events) => events.is_empty(),
+        }
+    }
+
+    /// Encodes the event table body, the inverse of [`parse_msg_body`].
+    fn encode(&self) -> Vec<u8> {
+        let mut pack = Vec::new();
+        match self {
+            Self::ValueEvents(events) => for ValueEvent(uuid, val) in events {
+                encode_uuid(uuid, &mut pack);
+                pack.write_f64::<LittleEndian>(*val).unwrap();
+            },
+            Self::TextEvents(events) => for TextEvent(uuid, uuid_icon, text) in events {
+                encode_uuid(uuid, &mut pack);
+                encode_uuid(uuid_icon, &mut pack);
+                pack.write_u32::<LittleEndian>(text.len().try_into().unwrap()).unwrap();
+                pack.extend_from_slice(text.as_bytes());
+                pack.resize(pack.len() + (4 - text.len() % 4) % 4, 0);
+            },
+            Self::DaytimerEvents(events) => for DaytimerEvent(uuid, default_val, entries) in events {
+                encode_uuid(uuid, &mut pack);
+                pack.write_f64::<LittleEndian>(*default_val).unwrap();
+                pack.write_i32::<LittleEndian>(entries.len().try_into().unwrap()).unwrap();
+                for entry in entries {
+                    pack.write_i32::<LittleEndian>(entry.mode).unwrap();
+                    pack.write_i32::<LittleEndian>(entry.from).unwrap();
+                    pack.write_i32::<LittleEndian>(entry.to).unwrap();
+                    pack.write_i32::<LittleEndian>(entry.need_activate).unwrap();
+                    pack.write_f64::<LittleEndian>(entry.value).unwrap();
+                }
+            },
+            Self::WeatherEvents(events) => for WeatherEvent(uuid, last_update, entries) in events {
+                encode_uuid(uuid, &mut pack);
+                pack.write_u32::<LittleEndian>(*last_update).unwrap();
+                pack.write_i32::<LittleEndian>(entries.len().try_into().unwrap()).unwrap();
+                for entry in entries {
+                    pack.write_i32::<LittleEndian>(entry.timestamp).unwrap();
+                    pack.write_i32::<LittleEndian>(entry.weather_type).unwrap();
+                    pack.write_i32::<LittleEndian>(entry.wind_direction).unwrap();
+                    pack.write_i32::<LittleEndian>(entry.solar_radiation).unwrap();
+                    pack.write_i32::<LittleEndian>(entry.relative_humidity).unwrap();
+                    pack.write_f64::<LittleEndian>(entry.temperature).unwrap();
+                    pack.write_f64::<LittleEndian>(entry.perceived_temperature).unwrap();
+                    pack.write_f64::<LittleEndian>(entry.dew_point).unwrap();
+                    pack.write_f64::<LittleEndian>(entry.precipitation).unwrap();
+                    pack.write_f64::<LittleEndian>(entry.wind_speed).unwrap();
+                    pack.write_f64::<LittleEndian>(entry.barometric_pressure).unwrap();
+                }
+            },
+        }
+        pack
+    }
+}
+
 impl Into<HashMap<LoxoneUUID, LoxoneState>> for EventTable {
     fn into(self) -> HashMap<LoxoneUUID, LoxoneState> {
         match self { // TODO
@@ -434,60 +953,59 @@ impl Into<HashMap<LoxoneUUID, LoxoneState>> for EventTable {
     }
 }
 
-fn hash_pwd(user: &str, pwd: &str, key: &[u8], salt: &str, hash_alg: &str) -> Vec<u8> {
+pub(crate) fn hash_pwd(user: &str, pwd: &str, key: &[u8], salt: &str, hash_alg: &str) -> Secret<Vec<u8>> {
     match hash_alg {
-        "SHA1" => {
-            let mut hasher = Sha1::new();
-            hasher.input_str(format!("{}:{}", pwd, salt).as_str());
-            let password_hash = hasher.result_str().to_uppercase();
-
-            let mut mac = Hmac::<Sha1>::new(Sha1::new(), key);
-            mac.input(format!("{}:{}", user, password_hash).as_bytes());
-
-            let mac_result = mac.result();
-            mac_result.code().to_vec()
-        }
-        "SHA256" => {
-            let mut hasher = Sha256::new();
-            hasher.input_str(format!("{}:{}", pwd, salt).as_str());
-            let password_hash = hasher.result_str().to_uppercase();
-
-            let mut mac = Hmac::<Sha256>::new(Sha256::new(), key);
-            mac.input(format!("{}:{}", user, password_hash).as_bytes());
-
-            let mac_result = mac.result();
-            mac_result.code().to_vec()
-        },
+        "SHA1" => hmac_pwd(Sha1::new(), user, pwd, key, salt),
+        "SHA256" => hmac_pwd(Sha256::new(), user, pwd, key, salt),
         _ => panic!("Can only use SHA1 and SHA256 here.")
     }
 }
 
-fn hash_token(token: &str, key: &[u8], hash_alg: &str) -> Vec<u8> {
-    match hash_alg {
-        "SHA1" => {
-            let mut mac = Hmac::<Sha1>::new(Sha1::new(), key);
-            mac.input(token.as_bytes());
-
-            let mac_result = mac.result();
-            mac_result.code().to_vec()
-        }
-        "SHA256" => {
-            let mut mac = Hmac::<Sha256>::new(Sha256::new(), key);
-            mac.input(token.as_bytes());
+fn hmac_pwd<D: Digest + Copy>(mut hasher: D, user: &str, pwd: &str, key: &[u8], salt: &str) -> Secret<Vec<u8>> {
+    let mut salted_pwd = Secret::with_capacity(pwd.len() + 1 + salt.len());
+    salted_pwd.push_str(pwd);
+    salted_pwd.push_str(":");
+    salted_pwd.push_str(salt);
+    hasher.input_str(salted_pwd.expose());
+    let mut password_hash = Secret::new(vec![0; hasher.output_bytes()]);
+    hasher.result(password_hash.expose_mut());
+    hasher.reset();
+
+    let mut user_hash = Secret::with_capacity(user.len() + 1 + 2 * password_hash.expose().len());
+    user_hash.push_str(user);
+    user_hash.push_str(":");
+    user_hash.push_hex(password_hash.expose(), true);
+    hmac(hasher, key, user_hash.expose().as_bytes())
+}
 
-            let mac_result = mac.result();
-            mac_result.code().to_vec()
-        },
+pub(crate) fn hash_token(token: &str, key: &[u8], hash_alg: &str) -> Secret<Vec<u8>> {
+    match hash_alg {
+        "SHA1" => hmac(Sha1::new(), key, token.as_bytes()),
+        "SHA256" => hmac(Sha256::new(), key, token.as_bytes()),
         _ => panic!("Can only use SHA1 and SHA256 here.")
     }
 }
 
-fn encrypt_cmd(cmd: &str, session: &Session) -> Result<Vec<u8>, symmetriccipher::SymmetricCipherError> {
-    let salted_cmd = format!("salt/{}/{}\0", hex::encode(session.salt), cmd);
+fn hmac<D: Digest>(hasher: D, key: &[u8], input: &[u8]) -> Secret<Vec<u8>> {
+    let mut mac = Hmac::new(hasher, key);
+    mac.input(input);
+    let mut code = Secret::new(vec![0; mac.output_bytes()]);
+    mac.raw_result(code.expose_mut());
+    mac.reset();
+    code
+}
 
-    let mut encryptor = aes::cbc_encryptor(aes::KeySize::KeySize256, &session.rsa_key, &session.rsa_iv, blockmodes::PkcsPadding);
+fn encrypt_cmd(cmd: &str, session: &Session) -> Result<Vec<u8>, symmetriccipher::SymmetricCipherError> {
+    let mut salted_cmd = Secret::with_capacity(5 + 2 * session.salt.len() + 1 + cmd.len() + 1);
+    salted_cmd.push_str("salt/");
+    salted_cmd.push_hex(&session.salt, false);
+    salted_cmd.push_str("/");
+    salted_cmd.push_str(cmd);
+    salted_cmd.push_str("\0");
+
+    let mut encryptor = aes::cbc_encryptor(aes::KeySize::KeySize256, session.rsa_key.expose(), session.rsa_iv.expose(), blockmodes::PkcsPadding);
     let mut final_result = Vec::<u8>::new();
-    let mut read_buffer = buffer::RefReadBuffer::new(salted_cmd.as_bytes());
+    let mut read_buffer = buffer::RefReadBuffer::new(salted_cmd.expose().as_bytes());
     let mut buffer = [0; 4096];
     let mut write_buffer = buffer::RefWriteBuffer::new(&mut buffer);
 
@@ -504,9 +1022,19 @@ fn encrypt_cmd(cmd: &str, session: &Session) -> Result<Vec<u8>, symmetriccipher:
     Ok(final_result)
 }
 
-fn encrypt_cmd_ws(endpoint: &str, cmd: &str, session: &Session) -> Result<String, symmetriccipher::SymmetricCipherError> {
+fn encrypt_cmd_ws(endpoint: &str, cmd: &str, session: &Session) -> Result<Secret<String>, symmetriccipher::SymmetricCipherError> {
     let encoded_cipher: String = url::form_urlencoded::byte_serialize(base64::encode_config(encrypt_cmd(cmd, session)?, base64::STANDARD_NO_PAD).as_bytes()).collect();
-    Ok(format!("jdev/sys/{}/{}", endpoint, encoded_cipher))
+    Ok(Secret::new(format!("jdev/sys/{}/{}", endpoint, encoded_cipher)))
+}
+
+/// Returns the command with the payload of encrypted and authentication commands redacted, for logging and recording.
+pub(crate) fn redact_cmd(cmd: &str) -> Cow<'_, str> {
+    for endpoint in &["/enc/", "/fenc/", "/keyexchange/", "/getjwt/", "/gettoken/", "/refreshjwt/", "authwithtoken/"] {
+        if let Some(idx) = cmd.find(endpoint) {
+            return Cow::Owned(format!("{}***", &cmd[..idx + endpoint.len()]));
+        }
+    }
+    Cow::Borrowed(cmd)
 }
 
 fn parse_cert(cert: &str) -> Result<RSAPublicKey, X509CertError> {
@@ -523,169 +1051,406 @@ fn parse_cert(cert: &str) -> Result<RSAPublicKey, X509CertError> {
     }
 }
 
-async fn parse_msg_next<S: StreamExt<Item=tungstenite::Message> + Unpin>(stream: &mut S) -> Result<Message, tungstenite::Error> {
-    match stream.next().await.unwrap() {
+pub(crate) async fn parse_msg_next<S: StreamExt<Item=Result<tungstenite::Message, tungstenite::Error>> + Unpin>(stream: &mut S) -> Result<Message, ParseMessageError> {
+    match next_frame(stream).await? {
+        tungstenite::Message::Close(_) => Err(tungstenite::Error::ConnectionClosed.into()),
         tungstenite::Message::Binary(msg) => {
-            match parse_msg_header(&msg) {
-                (msg_type, Some(msg_len)) =>
-                    Ok(parse_msg_body(msg_type, msg_len.try_into().unwrap(), stream).await),
-                (msg_type, None) =>
-                    Ok(parse_msg_body(msg_type, parse_msg_len(stream.next().await.unwrap()), stream).await)
+            match parse_msg_header(&msg)? {
+                (msg_type, Some(msg_len)) => {
+                    tracing::trace!(?msg_type, msg_len, "message header");
+                    parse_msg_body(msg_type, msg_len, stream).await
+                },
+                (msg_type, None) => {
+                    let msg_len = parse_msg_len(next_frame(stream).await?)?;
+                    tracing::trace!(?msg_type, msg_len, "estimated message header");
+                    parse_msg_body(msg_type, msg_len, stream).await
+                }
             }
         },
-        msg => panic!("invalid message header {:?}", msg)
-    }
-}
-
-fn parse_msg_header(mut header: &[u8]) -> (MessageType, Option<usize>) {
-    assert_eq!(header[0], header.read_u8().unwrap());
-    let msg_type = MessageType::try_from(header.read_u8().unwrap()).unwrap();
-    let msg_info = header.read_u8().unwrap();
-    header.read_u8().unwrap();
-    match msg_info {
-        0 => (msg_type, Some(header.read_u32::<LittleEndian>().unwrap().try_into().unwrap())),
-        _ => (msg_type, None)
+        msg => Err(ParseMessageError::InvalidMessage(format!("invalid message header {:?}", msg))),
     }
 }
 
-fn parse_msg_len(header_msg: tungstenite::Message) -> u64 {
-    let mut header = Cursor::new(header_msg.into_data());
-    header.read_u32::<LittleEndian>().unwrap().try_into().unwrap()
+async fn next_frame<S: StreamExt<Item=Result<tungstenite::Message, tungstenite::Error>> + Unpin>(stream: &mut S) -> Result<tungstenite::Message, tungstenite::Error> {
+    stream.next().await.unwrap_or(Err(tungstenite::Error::ConnectionClosed))
 }
 
-async fn parse_msg_body<S: StreamExt<Item=tungstenite::Message> + Unpin>(msg_type: MessageType, msg_len: u64, stream: &mut S) -> Message {
-    match msg_type {
-        MessageType::Text => {
-            match stream.next().await.unwrap() {
-                tungstenite::Message::Text(body_msg) => Message::Text(body_msg),
-                msg => panic!("invalid message body {:?}", msg)
+fn parse_msg_header(header: &[u8]) -> Result<(MessageType, Option<u64>), ParseMessageError> {
+    let invalid = || ParseMessageError::InvalidMessage(format!("invalid message header {:02x?}", header));
+    match header {
+        [0x03, msg_type, msg_info, _, len @ ..] if len.len() == 4 => {
+            let msg_type = MessageType::try_from(*msg_type).map_err(|_| invalid())?;
+            match msg_info {
+                0 => Ok((msg_type, Some(u64::from(Cursor::new(len).read_u32::<LittleEndian>().map_err(|_| invalid())?)))),
+                _ => Ok((msg_type, None)),
             }
         },
-        MessageType::BinaryFile => {
-            match stream.next().await.unwrap() {
-                tungstenite::Message::Text(body_msg) => Message::BinaryText(body_msg),
-                tungstenite::Message::Binary(body_msg) => Message::BinaryFile(body_msg),
-                msg => panic!("invalid message body {:?}", msg)
-            }
-        },
-        MessageType::ValueEventTable => {
-            match stream.next().await.unwrap() {
-                tungstenite::Message::Binary(body_msg) => {
-                    let mut pack = Cursor::new(body_msg);
-                    let mut events: Vec<ValueEvent> = Vec::new();
-                    while pack.position() < msg_len {
-                        let uuid = parse_uuid(&mut pack);
-                        let val = pack.read_f64::<LittleEndian>().unwrap();
-                        events.push(ValueEvent(uuid, val));
-                    }
-                    Message::EventTable(EventTable::ValueEvents(events))
-                },
-                msg => panic!("invalid message body {:?}", msg)
-            }
+        _ => Err(invalid()),
+    }
+}
+
+/// Returns the length of the exact header following an estimated one.
+fn parse_msg_len(header_msg: tungstenite::Message) -> Result<u64, ParseMessageError> {
+    match header_msg {
+        tungstenite::Message::Binary(header) => match parse_msg_header(&header)? {
+            (_, Some(msg_len)) => Ok(msg_len),
+            (_, None) => Err(ParseMessageError::InvalidMessage(String::from("estimated message header follows estimated message header"))),
         },
-        MessageType::TextEventTable => {
-            match stream.next().await.unwrap() {
-                tungstenite::Message::Binary(body_msg) => {
-                    let mut pack = Cursor::new(body_msg);
-                    let mut events: Vec<TextEvent> = Vec::new();
-                    while pack.position() < msg_len {
-                        let uuid = parse_uuid(&mut pack);
-                        let uuid_icon = parse_uuid(&mut pack);
-                        let text_len = pack.read_u32::<LittleEndian>().unwrap().try_into().unwrap();
-                        let mut text_buf = vec![0; text_len];
-                        pack.read_exact(&mut text_buf).unwrap();
-                        let text = String::from_utf8(text_buf).unwrap();
-                        events.push(TextEvent(uuid, uuid_icon, text));
-                        match text_len % 4 {
-                            0 => (),
-                            r => {
-                                pack.seek(SeekFrom::Current((4 - r).try_into().unwrap())).unwrap();
-                            }
-                        }
-                    }
-                    Message::EventTable(EventTable::TextEvents(events))
-                },
-                msg => panic!("invalid message body {:?}", msg)
-            }
+        msg => Err(ParseMessageError::InvalidMessage(format!("invalid message header {:?}", msg))),
+    }
+}
+
+async fn parse_msg_body<S: StreamExt<Item=Result<tungstenite::Message, tungstenite::Error>> + Unpin>(msg_type: MessageType, msg_len: u64, stream: &mut S) -> Result<Message, ParseMessageError> {
+    let body = match msg_type {
+        MessageType::OutOfServiceIndicator => return Ok(Message::OutOfServiceIndicator),
+        MessageType::KeepAlive => return Ok(Message::KeepAlive),
+        _ => next_frame(stream).await?,
+    };
+    let invalid_body = |msg_type: MessageType, err: Option<io::Error>| ParseMessageError::InvalidMessage(match err {
+        Some(err) => format!("invalid {:?} message body: {}", msg_type, err),
+        None => format!("invalid {:?} message body", msg_type),
+    });
+    match (msg_type, body) {
+        (MessageType::Text, tungstenite::Message::Text(body_msg)) => Ok(Message::Text(body_msg)),
+        (MessageType::BinaryFile, tungstenite::Message::Text(body_msg)) => Ok(Message::BinaryText(body_msg)),
+        (MessageType::BinaryFile, tungstenite::Message::Binary(body_msg)) => Ok(Message::BinaryFile(body_msg)),
+        (MessageType::ValueEventTable, tungstenite::Message::Binary(body_msg)) => parse_value_events(body_msg, msg_len)
+            .map(|events| Message::EventTable(EventTable::ValueEvents(events)))
+            .map_err(|err| invalid_body(MessageType::ValueEventTable, Some(err))),
+        (MessageType::TextEventTable, tungstenite::Message::Binary(body_msg)) => parse_text_events(body_msg, msg_len)
+            .map(|events| Message::EventTable(EventTable::TextEvents(events)))
+            .map_err(|err| invalid_body(MessageType::TextEventTable, Some(err))),
+        (MessageType::DaytimerEventTable, tungstenite::Message::Binary(body_msg)) => parse_daytimer_events(body_msg, msg_len)
+            .map(|events| Message::EventTable(EventTable::DaytimerEvents(events)))
+            .map_err(|err| invalid_body(MessageType::DaytimerEventTable, Some(err))),
+        (MessageType::WeatherEventTable, tungstenite::Message::Binary(body_msg)) => parse_weather_events(body_msg, msg_len)
+            .map(|events| Message::EventTable(EventTable::WeatherEvents(events)))
+            .map_err(|err| invalid_body(MessageType::WeatherEventTable, Some(err))),
+        (msg_type, _) => Err(invalid_body(msg_type, None)),
+    }
+}
+
+fn parse_value_events(body: Vec<u8>, msg_len: u64) -> io::Result<Vec<ValueEvent>> {
+    let mut pack = Cursor::new(body);
+    let mut events: Vec<ValueEvent> = Vec::new();
+    while pack.position() < msg_len {
+        let uuid = parse_uuid(&mut pack)?;
+        let val = pack.read_f64::<LittleEndian>()?;
+        events.push(ValueEvent(uuid, val));
+    }
+    Ok(events)
+}
+
+fn parse_text_events(body: Vec<u8>, msg_len: u64) -> io::Result<Vec<TextEvent>> {
+    let mut pack = Cursor::new(body);
+    let mut events: Vec<TextEvent> = Vec::new();
+    while pack.position() < msg_len {
+        let uuid = parse_uuid(&mut pack)?;
+        let uuid_icon = parse_uuid(&mut pack)?;
+        let text_len = pack.read_u32::<LittleEndian>()?;
+        let mut text_buf = Vec::new();
+        (&mut pack).take(u64::from(text_len)).read_to_end(&mut text_buf)?;
+        if text_buf.len() as u64 != u64::from(text_len) {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
         }
-        MessageType::DaytimerEventTable => {
-            match stream.next().await.unwrap() {
-                tungstenite::Message::Binary(body_msg) => {
-                    let mut pack = Cursor::new(body_msg);
-                    let mut events: Vec<DaytimerEvent> = Vec::new();
-                    while pack.position() < msg_len {
-                        let uuid = parse_uuid(&mut pack);
-                        let default_val = pack.read_f64::<LittleEndian>().unwrap();
-                        let entries_len: usize = pack.read_i32::<LittleEndian>().unwrap().try_into().unwrap();
-                        let mut entries: Vec<LoxoneDaytimerEntry> = Vec::new();
-                        for _ in 0..entries_len {
-                            let mode = pack.read_i32::<LittleEndian>().unwrap();
-                            let from = pack.read_i32::<LittleEndian>().unwrap();
-                            let to = pack.read_i32::<LittleEndian>().unwrap();
-                            let need_activate = pack.read_i32::<LittleEndian>().unwrap();
-                            let value = pack.read_f64::<LittleEndian>().unwrap();
-                            entries.push(LoxoneDaytimerEntry{ mode, from, to, need_activate, value })
-                        }
-                        events.push(DaytimerEvent(uuid, default_val, entries))
-                    }
-                    Message::EventTable(EventTable::DaytimerEvents(events))
-                },
-                msg => panic!("invalid message body {:?}", msg)
+        let text = String::from_utf8(text_buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        events.push(TextEvent(uuid, uuid_icon, text));
+        match text_len % 4 {
+            0 => (),
+            r => {
+                pack.seek(SeekFrom::Current(i64::from(4 - r)))?;
             }
-        },
-        MessageType::OutOfServiceIndicator => Message::OutOfServiceIndicator,
-        MessageType::KeepAlive => Message::KeepAlive,
-        MessageType::WeatherEventTable => {
-            match stream.next().await.unwrap() {
-                tungstenite::Message::Binary(body_msg) => {
-                    let mut pack = Cursor::new(body_msg);
-                    let mut events: Vec<WeatherEvent> = Vec::new();
-                    while pack.position() < msg_len {
-                        let uuid = parse_uuid(&mut pack);
-                        let last_update = pack.read_u32::<LittleEndian>().unwrap();
-                        let entries_len: usize = pack.read_i32::<LittleEndian>().unwrap().try_into().unwrap();
-                        let mut entries: Vec<LoxoneWeatherEntry> = Vec::new();
-                        for _ in 0..entries_len {
-                            let timestamp = pack.read_i32::<LittleEndian>().unwrap();
-                            let weather_type = pack.read_i32::<LittleEndian>().unwrap();
-                            let wind_direction = pack.read_i32::<LittleEndian>().unwrap();
-                            let solar_radiation = pack.read_i32::<LittleEndian>().unwrap();
-                            let relative_humidity = pack.read_i32::<LittleEndian>().unwrap();
-                            let temperature = pack.read_f64::<LittleEndian>().unwrap();
-                            let perceived_temperature = pack.read_f64::<LittleEndian>().unwrap();
-                            let dew_point = pack.read_f64::<LittleEndian>().unwrap();
-                            let precipitation = pack.read_f64::<LittleEndian>().unwrap();
-                            let wind_speed = pack.read_f64::<LittleEndian>().unwrap();
-                            let barometic_pressure = pack.read_f64::<LittleEndian>().unwrap();
-                            entries.push(LoxoneWeatherEntry{
-                                timestamp,
-                                weather_type,
-                                wind_direction,
-                                solar_radiation,
-                                relative_humidity,
-                                temperature,
-                                perceived_temperature,
-                                dew_point,
-                                precipitation,
-                                wind_speed,
-                                barometic_pressure
-                            })
-                        }
-                        events.push(WeatherEvent(uuid, last_update, entries))
-                    }
-                    Message::EventTable(EventTable::WeatherEvents(events))
-                },
-                msg => panic!("invalid message body {:?}", msg)
-            }
-        },
+        }
+    }
+    Ok(events)
+}
+
+fn parse_daytimer_events(body: Vec<u8>, msg_len: u64) -> io::Result<Vec<DaytimerEvent>> {
+    let mut pack = Cursor::new(body);
+    let mut events: Vec<DaytimerEvent> = Vec::new();
+    while pack.position() < msg_len {
+        let uuid = parse_uuid(&mut pack)?;
+        let default_val = pack.read_f64::<LittleEndian>()?;
+        let entries_len = parse_entries_len(&mut pack)?;
+        let mut entries: Vec<LoxoneDaytimerEntry> = Vec::new();
+        for _ in 0..entries_len {
+            let mode = pack.read_i32::<LittleEndian>()?;
+            let from = pack.read_i32::<LittleEndian>()?;
+            let to = pack.read_i32::<LittleEndian>()?;
+            let need_activate = pack.read_i32::<LittleEndian>()?;
+            let value = pack.read_f64::<LittleEndian>()?;
+            entries.push(LoxoneDaytimerEntry{ mode, from, to, need_activate, value })
+        }
+        events.push(DaytimerEvent(uuid, default_val, entries))
+    }
+    Ok(events)
+}
+
+fn parse_weather_events(body: Vec<u8>, msg_len: u64) -> io::Result<Vec<WeatherEvent>> {
+    let mut pack = Cursor::new(body);
+    let mut events: Vec<WeatherEvent> = Vec::new();
+    while pack.position() < msg_len {
+        let uuid = parse_uuid(&mut pack)?;
+        let last_update = pack.read_u32::<LittleEndian>()?;
+        let entries_len = parse_entries_len(&mut pack)?;
+        let mut entries: Vec<LoxoneWeatherEntry> = Vec::new();
+        for _ in 0..entries_len {
+            let timestamp = pack.read_i32::<LittleEndian>()?;
+            let weather_type = pack.read_i32::<LittleEndian>()?;
+            let wind_direction = pack.read_i32::<LittleEndian>()?;
+            let solar_radiation = pack.read_i32::<LittleEndian>()?;
+            let relative_humidity = pack.read_i32::<LittleEndian>()?;
+            let temperature = pack.read_f64::<LittleEndian>()?;
+            let perceived_temperature = pack.read_f64::<LittleEndian>()?;
+            let dew_point = pack.read_f64::<LittleEndian>()?;
+            let precipitation = pack.read_f64::<LittleEndian>()?;
+            let wind_speed = pack.read_f64::<LittleEndian>()?;
+            let barometric_pressure = pack.read_f64::<LittleEndian>()?;
+            entries.push(LoxoneWeatherEntry{
+                timestamp,
+                weather_type,
+                wind_direction,
+                solar_radiation,
+                relative_humidity,
+                temperature,
+                perceived_temperature,
+                dew_point,
+                precipitation,
+                wind_speed,
+                barometric_pressure
+            })
+        }
+        events.push(WeatherEvent(uuid, last_update, entries))
     }
+    Ok(events)
 }
 
-fn parse_uuid(pack: &mut Cursor<Vec<u8>>) -> LoxoneUUID {
-    let d1 = pack.read_u32::<LittleEndian>().unwrap();
-    let d2 = pack.read_u16::<LittleEndian>().unwrap();
-    let d3 = pack.read_u16::<LittleEndian>().unwrap();
+fn parse_entries_len(pack: &mut Cursor<Vec<u8>>) -> io::Result<usize> {
+    usize::try_from(pack.read_i32::<LittleEndian>()?).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+fn parse_uuid(pack: &mut Cursor<Vec<u8>>) -> io::Result<LoxoneUUID> {
+    let d1 = pack.read_u32::<LittleEndian>()?;
+    let d2 = pack.read_u16::<LittleEndian>()?;
+    let d3 = pack.read_u16::<LittleEndian>()?;
     let mut d4 = [0; 8];
-    pack.read_exact(&mut d4).unwrap();
-    format!("{:08x}-{:04x}-{:04x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}", d1, d2, d3, d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7])
+    pack.read_exact(&mut d4)?;
+    Ok(format!("{:08x}-{:04x}-{:04x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}", d1, d2, d3, d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7]))
+}
+
+/// Encodes a text message as sent by the Miniserver in reply to a command.
+pub fn encode_text_msg(text: String) -> Vec<tungstenite::Message> {
+    vec![encode_msg_header(MessageType::Text, text.len()), tungstenite::Message::Text(text)]
+}
+
+/// Encodes a binary file message, whose body is either a text (e.g. `LoxAPP3.json`) or a binary frame.
+pub fn encode_file_msg(body: tungstenite::Message) -> Vec<tungstenite::Message> {
+    vec![encode_msg_header(MessageType::BinaryFile, body.len()), body]
+}
+
+/// Encodes the keep-alive reply header.
+pub fn encode_keepalive_msg() -> tungstenite::Message {
+    encode_msg_header(MessageType::KeepAlive, 0)
+}
+
+/// Encodes the out-of-service indicator header.
+pub fn encode_out_of_service_msg() -> tungstenite::Message {
+    encode_msg_header(MessageType::OutOfServiceIndicator, 0)
+}
+
+/// Encodes the given states as value, text, daytimer and weather event table messages, in this order.
+///
+/// Empty tables are skipped unless `include_empty` is set, as for the initial state sent after enabling status updates.
+pub fn encode_event_tables<I: IntoIterator<Item = (LoxoneUUID, LoxoneState)>>(states: I, include_empty: bool) -> Vec<tungstenite::Message> {
+    let (mut values, mut texts, mut daytimers, mut weathers) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for (uuid, state) in states {
+        match state {
+            LoxoneState::Value(val) => values.push(ValueEvent(uuid, val)),
+            LoxoneState::Text(text, uuid_icon) => texts.push(TextEvent(uuid, uuid_icon, text)),
+            LoxoneState::Daytimer(entries, default_val) => daytimers.push(DaytimerEvent(uuid, default_val, entries)),
+            LoxoneState::Weather(entries, last_update) => weathers.push(WeatherEvent(uuid, last_update, entries)),
+        }
+    }
+    let event_tables = vec![
+        EventTable::ValueEvents(values),
+        EventTable::TextEvents(texts),
+        EventTable::DaytimerEvents(daytimers),
+        EventTable::WeatherEvents(weathers),
+    ];
+    event_tables.into_iter()
+        .filter(|event_table| include_empty || !event_table.is_empty())
+        .flat_map(|event_table| {
+            let body = event_table.encode();
+            vec![encode_msg_header(event_table.msg_type(), body.len()), tungstenite::Message::Binary(body)]
+        })
+        .collect()
+}
+
+fn encode_msg_header(msg_type: MessageType, msg_len: usize) -> tungstenite::Message {
+    let mut header = vec![0x03, msg_type as u8, 0x00, 0x00];
+    header.write_u32::<LittleEndian>(msg_len.try_into().unwrap()).unwrap();
+    tungstenite::Message::Binary(header)
+}
+
+/// Inverse of [`parse_uuid`], writing zeroes for malformed UUIDs.
+fn encode_uuid(uuid: &str, pack: &mut Vec<u8>) {
+    let parts: Vec<&str> = uuid.split('-').collect();
+    let fields = match parts.as_slice() {
+        [d1, d2, d3, d4] => u32::from_str_radix(d1, 16).ok()
+            .zip(u16::from_str_radix(d2, 16).ok())
+            .zip(u16::from_str_radix(d3, 16).ok())
+            .zip(hex::decode(d4).ok().filter(|d4| d4.len() == 8)),
+        _ => None,
+    };
+    let (((d1, d2), d3), d4) = fields.unwrap_or((((0, 0), 0), vec![0; 8]));
+    pack.write_u32::<LittleEndian>(d1).unwrap();
+    pack.write_u16::<LittleEndian>(d2).unwrap();
+    pack.write_u16::<LittleEndian>(d3).unwrap();
+    pack.extend_from_slice(&d4);
+}
+
+fn detect_content_type(data: &[u8]) -> &'static str {
+    let head = String::from_utf8_lossy(&data[..data.len().min(256)]);
+    let head = head.trim_start();
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if data.starts_with(&[0xff, 0xd8, 0xff]) {
+        "image/jpeg"
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        "image/gif"
+    } else if head.starts_with("<svg") || (head.starts_with("<?xml") && head.contains("<svg")) {
+        "image/svg+xml"
+    } else if head.starts_with("<?xml") {
+        "application/xml"
+    } else if head.starts_with('{') || head.starts_with('[') {
+        "application/json"
+    } else if std::str::from_utf8(data).is_ok() {
+        "text/plain"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn parse(frames: Vec<tungstenite::Message>) -> Result<Message, ParseMessageError> {
+        parse_msg_next(&mut stream::iter(frames.into_iter().map(Ok))).await
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_frames() {
+        let truncated = vec![encode_msg_header(MessageType::ValueEventTable, 24), tungstenite::Message::Binary(vec![0; 20])];
+        assert!(matches!(parse(truncated).await, Err(ParseMessageError::InvalidMessage(_))));
+        let unknown_type = vec![tungstenite::Message::Binary(vec![0x03, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])];
+        assert!(matches!(parse(unknown_type).await, Err(ParseMessageError::InvalidMessage(_))));
+        let short_header = vec![tungstenite::Message::Binary(vec![0x03, 0x00])];
+        assert!(matches!(parse(short_header).await, Err(ParseMessageError::InvalidMessage(_))));
+        let text_header_binary_body = vec![encode_msg_header(MessageType::Text, 4), tungstenite::Message::Binary(vec![0; 4])];
+        assert!(matches!(parse(text_header_binary_body).await, Err(ParseMessageError::InvalidMessage(_))));
+    }
+
+    #[tokio::test]
+    async fn propagates_transport_errors() {
+        let mut frames = stream::iter(vec![Ok(encode_msg_header(MessageType::Text, 4)), Err(tungstenite::Error::AlreadyClosed)]);
+        assert!(matches!(parse_msg_next(&mut frames).await, Err(ParseMessageError::Transport(tungstenite::Error::AlreadyClosed))));
+        assert!(matches!(parse(Vec::new()).await, Err(ParseMessageError::Transport(tungstenite::Error::ConnectionClosed))));
+    }
+
+    #[tokio::test]
+    async fn reads_exact_header_after_estimated_one() {
+        let estimated = tungstenite::Message::Binary(vec![0x03, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        let mut frames = vec![estimated];
+        frames.extend(encode_file_msg(tungstenite::Message::Binary(vec![1, 2, 3])));
+        assert!(matches!(parse(frames).await, Ok(Message::BinaryFile(data)) if data == [1, 2, 3]));
+    }
+
+    #[test]
+    fn hashes_credentials_like_the_miniserver() {
+        let key = b"0123456789abcdef";
+        let mut hasher = Sha256::new();
+        hasher.input_str("secret:4f1d");
+        let mut mac = Hmac::new(Sha256::new(), key);
+        mac.input(format!("admin:{}", hasher.result_str().to_uppercase()).as_bytes());
+        assert_eq!(hash_pwd("admin", "secret", key, "4f1d", "SHA256").expose(), mac.result().code());
+
+        let mut mac = Hmac::new(Sha1::new(), key);
+        mac.input(b"eyJ0eXAi.eyJ1c2VyIjoiYWRtaW4ifQ.c2ln");
+        assert_eq!(hash_token("eyJ0eXAi.eyJ1c2VyIjoiYWRtaW4ifQ.c2ln", key, "SHA1").expose(), mac.result().code());
+    }
+
+    #[test]
+    fn builds_secrets_in_place() {
+        let mut secret = Secret::with_capacity(2);
+        secret.push_str("salt/");
+        secret.push_hex(&[0x0a, 0xf3], false);
+        secret.push_str("/");
+        secret.push_hex(&[0xbe], true);
+        assert_eq!(secret.expose(), "salt/0af3/BE");
+    }
+
+    #[test]
+    fn parses_jwt() {
+        let jwt: LoxoneJwt = serde_json::from_value(serde_json::json!({
+            "token": "eyJ0eXAi.eyJ1c2VyIjoiYWRtaW4ifQ.c2ln",
+            "key": "4B45590A",
+            "validUntil": 547394710,
+            "tokenRights": 4,
+            "unsecurePass": false,
+        })).unwrap();
+        assert_eq!(jwt.token.expose(), "eyJ0eXAi.eyJ1c2VyIjoiYWRtaW4ifQ.c2ln");
+        assert_eq!(jwt.valid_until, LoxoneTimestamp(547394710));
+        assert!(!format!("{:?}", jwt).contains("eyJ0eXAi"));
+        assert_eq!(serde_json::to_value(&jwt).unwrap()["token"], "eyJ0eXAi.eyJ1c2VyIjoiYWRtaW4ifQ.c2ln");
+    }
+
+    async fn round_trip(states: Vec<(LoxoneUUID, LoxoneState)>) {
+        let mut frames = stream::iter(encode_event_tables(states.clone(), false).into_iter().map(Ok));
+        let parsed: HashMap<LoxoneUUID, LoxoneState> = match parse_msg_next(&mut frames).await {
+            Ok(Message::EventTable(event_table)) => event_table.into(),
+            other => panic!("unexpected message {:?}", other),
+        };
+        assert_eq!(parsed, states.into_iter().collect());
+        assert!(matches!(parse_msg_next(&mut frames).await, Err(ParseMessageError::Transport(tungstenite::Error::ConnectionClosed))));
+    }
+
+    fn uuid(n: u8) -> LoxoneUUID {
+        format!("1000{:04x}-0a00-0c00-ffff504f94a000{:02x}", n, n)
+    }
+
+    #[tokio::test]
+    async fn round_trips_value_events() {
+        round_trip(vec![(uuid(1), LoxoneState::Value(0.0)), (uuid(2), LoxoneState::Value(-21.5)), (uuid(3), LoxoneState::Value(f64::MAX))]).await;
+    }
+
+    #[tokio::test]
+    async fn round_trips_text_events_with_padding() {
+        let texts = ["", "a", "ab", "abc", "abcd", "abcde", "ä", "€uro"];
+        round_trip(texts.iter().enumerate().map(|(n, text)| (uuid(n as u8), LoxoneState::Text(text.to_string(), uuid(0xf0 + n as u8)))).collect()).await;
+    }
+
+    #[tokio::test]
+    async fn round_trips_daytimer_events() {
+        let entries = vec![
+            LoxoneDaytimerEntry { mode: 0, from: 0, to: 360, need_activate: 0, value: 18.0 },
+            LoxoneDaytimerEntry { mode: 3, from: 360, to: 1440, need_activate: 1, value: 21.5 },
+        ];
+        round_trip(vec![(uuid(1), LoxoneState::Daytimer(entries, 16.0)), (uuid(2), LoxoneState::Daytimer(Vec::new(), 0.0))]).await;
+    }
+
+    #[tokio::test]
+    async fn round_trips_weather_events() {
+        let entries = vec![
+            LoxoneWeatherEntry {
+                timestamp: 547394400, weather_type: 3, wind_direction: 270, solar_radiation: 120, relative_humidity: 81,
+                temperature: 12.5, perceived_temperature: 10.25, dew_point: 9.0, precipitation: 0.4, wind_speed: 14.0, barometric_pressure: 1013.0,
+            },
+            LoxoneWeatherEntry {
+                timestamp: 547398000, weather_type: 1, wind_direction: 0, solar_radiation: 0, relative_humidity: 64,
+                temperature: -3.0, perceived_temperature: -7.5, dew_point: -8.0, precipitation: 0.0, wind_speed: 0.0, barometric_pressure: 1020.5,
+            },
+        ];
+        round_trip(vec![(uuid(1), LoxoneState::Weather(entries, 547394710)), (uuid(2), LoxoneState::Weather(Vec::new(), 0))]).await;
+    }
 }
@@ -0,0 +1,260 @@
+use std::collections::{HashMap, HashSet};
+
+use loxone::loxapp3::{LoxoneApp3, LoxoneControl, LoxoneController, LoxoneStates, LoxoneUUID};
+
+const LOXAPP3: &str = include_str!("fixtures/LoxAPP3.json");
+
+fn structure() -> LoxoneApp3 {
+    serde_json::from_str(LOXAPP3).unwrap()
+}
+
+fn control<'a>(app: &'a LoxoneApp3, name: &str) -> &'a LoxoneControl {
+    app.controls.values().find(|control| control.name == name).unwrap()
+}
+
+#[test]
+fn parses_structure_file() {
+    let app = structure();
+    assert_eq!(app.controls.len(), 57);
+    assert_eq!(app.ms_info.ms_name, "Home");
+    assert_eq!(app.operating_modes[&2], "Party");
+    assert_eq!(app.autopilot.len(), 1);
+    assert!(app.weather_server.is_none());
+    assert!(app.unknown_controllers().is_empty());
+}
+
+#[test]
+fn parses_every_modeled_controller() {
+    let app = structure();
+    let raw: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+    for (uuid, control) in &app.controls {
+        let type_name = raw["controls"][uuid]["type"].as_str().unwrap();
+        assert!(!matches!(control.controller, LoxoneController::Unknown { .. }), "{} does not match the {} model", control.name, type_name);
+        assert_eq!(control.controller.type_name(), type_name);
+    }
+    let type_names: HashSet<&str> = app.controls.values().map(|control| control.controller.type_name()).collect();
+    assert_eq!(type_names.len(), 57);
+}
+
+#[test]
+fn defaults_missing_details() {
+    let app = structure();
+    match &control(&app, "Living Room Blinds").controller {
+        LoxoneController::Jalousie(jalousie) => {
+            assert_eq!(jalousie.details.animation, 0);
+            assert!(jalousie.details.is_automatic);
+            assert!(jalousie.states.target_position.is_none());
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+    match &control(&app, "Sprinklers").controller {
+        LoxoneController::Radio(radio) => {
+            assert_eq!(radio.details.outputs["2"], "Back Lawn");
+            assert_eq!(radio.details.all_off, "");
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+    match &control(&app, "Ventilation Level").controller {
+        LoxoneController::ValueSelector(selector) => assert!(!selector.details.increase_only),
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+    match &control(&app, "Door Log").controller {
+        LoxoneController::Tracker(tracker) => assert_eq!(tracker.details.max_entries, 0),
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+}
+
+#[test]
+fn parses_partial_states() {
+    let app = structure();
+    match &control(&app, "Living Room Music").controller {
+        LoxoneController::AudioZone(zone) => {
+            assert!(zone.states.volume.is_some());
+            assert!(zone.states.station.is_none());
+            assert!(zone.states.source_list.is_none());
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+    match &control(&app, "Sauna").controller {
+        LoxoneController::Sauna(sauna) => {
+            assert!(sauna.details.has_door_sensor);
+            assert!(sauna.states.temp_actual.is_some());
+            assert!(sauna.states.humidity_actual.is_none());
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+}
+
+#[test]
+fn parses_info_only_controls() {
+    let app = structure();
+    match &control(&app, "Outdoor Temperature").controller {
+        LoxoneController::InfoOnlyAnalog(info) => {
+            assert_eq!(info.details.format, "%.1f°");
+            assert!(!info.states.value.is_empty());
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+    match &control(&app, "Front Door").controller {
+        LoxoneController::InfoOnlyDigital(info) => {
+            assert_eq!(info.states.active, "100633a1-0a5f-0c5f-ffff504f94a0005f");
+            assert_eq!(info.details.text.as_ref().unwrap().on, "Open");
+            assert_eq!(info.details.color.as_ref().unwrap().off, "#69C350");
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+}
+
+#[test]
+fn parses_sub_controls() {
+    let app = structure();
+    let lights = control(&app, "Living Room Lights");
+    let sub_controls = lights.controller.sub_controls().unwrap();
+    assert_eq!(sub_controls.len(), 2);
+    assert!(sub_controls.values().any(|sub_control| matches!(sub_control.controller, LoxoneController::Dimmer(_))));
+    assert!(sub_controls.values().any(|sub_control| matches!(sub_control.controller, LoxoneController::Switch(_))));
+}
+
+fn parse_control(controller: serde_json::Value) -> LoxoneControl {
+    let mut control = serde_json::json!({
+        "name": "Test",
+        "uuidAction": "0f2c4a8e-0041-1b2a-ffff504f94a0c0ff",
+        "defaultRating": 0,
+        "isFavorite": false,
+        "isSecured": false,
+    });
+    control.as_object_mut().unwrap().extend(controller.as_object().unwrap().clone());
+    serde_json::from_value(control).unwrap()
+}
+
+#[test]
+fn keeps_unknown_controller_type() {
+    let control = parse_control(serde_json::json!({
+        "type": "CustomWidget",
+        "details": { "mode": 3 },
+        "states": { "value": "0f2c4a8e-0042-1b2a-ffff504f94a0c0ff" },
+    }));
+    match &control.controller {
+        LoxoneController::Unknown { type_name, raw } => {
+            assert_eq!(type_name, "CustomWidget");
+            assert_eq!(raw["details"]["mode"], 3);
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+    assert_eq!(control.name, "Test");
+    assert!(control.other.is_empty());
+}
+
+#[test]
+fn falls_back_to_unknown_on_mismatched_content() {
+    let control = parse_control(serde_json::json!({
+        "type": "Switch",
+        "states": { "active": 1 },
+    }));
+    match &control.controller {
+        LoxoneController::Unknown { type_name, raw } => {
+            assert_eq!(type_name, "Switch");
+            assert_eq!(raw["states"]["active"], 1);
+        },
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+}
+
+#[test]
+fn reports_unmodeled_room_controller_as_unknown() {
+    let mut app: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+    let uuid = "0f2c4a8e-0043-1b2a-ffff504f94a0c0ff";
+    app["controls"][uuid] = serde_json::json!({
+        "name": "Bedroom Climate",
+        "type": "IntelligentRoomControllerv2",
+        "uuidAction": uuid,
+        "defaultRating": 0,
+        "isFavorite": false,
+        "isSecured": false,
+        "details": { "timerModes": [] },
+        "states": { "tempActual": "0f2c4a8e-0044-1b2a-ffff504f94a0c0ff" },
+    });
+    let app: LoxoneApp3 = serde_json::from_value(app).unwrap();
+    assert_eq!(app.unknown_controllers(), vec![(&uuid.to_owned(), "IntelligentRoomControllerv2")]);
+    match &app.controls[uuid].controller {
+        LoxoneController::Unknown { raw, .. } => assert!(raw["states"]["tempActual"].is_string()),
+        other => panic!("unexpected controller {}", other.type_name()),
+    }
+}
+
+#[test]
+fn lists_state_uuids() {
+    let app = structure();
+    let states = control(&app, "Living Room Music").controller.states();
+    assert_eq!(states.len(), 11);
+    assert!(states.contains_key("maxVolume"));
+    assert!(!states.contains_key("station"));
+    let global_states = app.global_states.state_uuids();
+    assert_eq!(global_states.len(), 13);
+    assert!(global_states.iter().any(|(name, uuid)| *name == "miniserverTime" && **uuid == app.global_states.miniserver_time));
+}
+
+#[test]
+fn lists_state_uuids_of_unknown_controllers() {
+    let control = parse_control(serde_json::json!({
+        "type": "CustomWidget",
+        "states": { "value": "0f2c4a8e-0042-1b2a-ffff504f94a0c0ff", "limits": [1, 2] },
+    }));
+    assert_eq!(control.controller.state_uuids(), vec![("value", &"0f2c4a8e-0042-1b2a-ffff504f94a0c0ff".to_owned())]);
+}
+
+#[test]
+fn resolves_override_states() {
+    let app = structure();
+    let index = app.index();
+    let owner = index.state_owner("20000333-0b03-0c03-ffff504f94a00003").unwrap();
+    assert_eq!((owner.control.name, owner.state_name), ("Heating Schedule", "override"));
+    let owner = index.state_owner("20000fff-0b0f-0c0f-ffff504f94a0000f").unwrap();
+    assert_eq!((owner.control.name, owner.state_name), ("Bedroom Climate", "override"));
+}
+
+#[test]
+fn state_uuids_match_structure_file() {
+    let app = structure();
+    let controls: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+    for (uuid, control) in &app.controls {
+        let states: HashMap<String, LoxoneUUID> = match &controls["controls"][uuid]["states"] {
+            serde_json::Value::Null => HashMap::new(),
+            states => serde_json::from_value(states.clone()).unwrap(),
+        };
+        assert_eq!(control.controller.states(), states, "{}", control.name);
+    }
+}
+
+#[test]
+fn serializes_structure_file_losslessly() {
+    let app = structure();
+    let expected: serde_json::Value = serde_json::from_str(LOXAPP3).unwrap();
+    assert_eq!(serde_json::to_value(&app).unwrap(), expected);
+}
+
+#[test]
+fn skips_missing_meter_type() {
+    let controller = serde_json::json!({
+        "type": "Meter",
+        "details": { "actualFormat": "%.1fkW", "totalFormat": "%.1fkWh" },
+        "states": { "actual": "0f2c4a8e-0042-1b2a-ffff504f94a0c0ff" },
+    });
+    let control = parse_control(controller.clone());
+    assert!(matches!(control.controller, LoxoneController::Meter(_)));
+    assert_eq!(serde_json::to_value(&control).unwrap()["details"], controller["details"]);
+}
+
+#[test]
+fn serializes_unknown_controller_with_details_and_states() {
+    let controller = serde_json::json!({
+        "type": "IntelligentRoomControllerv2",
+        "details": { "timerModes": [] },
+        "states": { "tempActual": "0f2c4a8e-0044-1b2a-ffff504f94a0c0ff" },
+    });
+    let control = parse_control(controller.clone());
+    let serialized = serde_json::to_value(&control).unwrap();
+    assert_eq!(serialized["details"], controller["details"]);
+    assert_eq!(serialized["states"], controller["states"]);
+    assert!(serialized.get("room").is_none());
+}
@@ -0,0 +1,76 @@
+use loxone::LoxoneTimestamp;
+use loxone::loxapp3::LoxoneMessage;
+use loxone::loxapp3::message_center::{LoxoneMessageCenter, LoxoneMessageEntry, LoxoneMessageSeverity};
+use loxone::loxapp3::states::{decode_message_center_text_state, LoxoneTextState};
+
+const MESSAGE_CENTER: &str = include_str!("fixtures/message_center.json");
+
+fn message_center() -> LoxoneMessageCenter {
+    serde_json::from_str(MESSAGE_CENTER).unwrap()
+}
+
+fn titles<'a>(entries: impl Iterator<Item = &'a LoxoneMessageEntry>) -> Vec<&'a str> {
+    entries.map(|entry| entry.title.as_deref().unwrap()).collect()
+}
+
+#[test]
+fn decodes_message_center() {
+    let message_center = message_center();
+    assert_eq!(message_center.entries.len(), 5);
+    assert_eq!(message_center.other["version"], 2);
+
+    let offline = &message_center.entries[0];
+    assert_eq!(offline.entry_uuid, "0f2c4a8e-0101-1b2a-ffff504f94a0c0ff");
+    assert_eq!((offline.event_id, offline.source_uuid.as_deref()), (Some(1002), Some("10004444-0a04-0c04-ffff504f94a00004")));
+    assert_eq!((offline.affected_name.as_deref(), offline.installation_place.as_deref()), (Some("Tree Extension"), Some("Distribution board")));
+    assert_eq!(offline.room_uuid.as_deref(), Some("10001111-0a01-0c01-ffff504f94a00001"));
+    assert_eq!(offline.timestamps, [LoxoneTimestamp(558_316_800), LoxoneTimestamp(558_403_200)]);
+    assert_eq!(offline.last_seen(), Some(LoxoneTimestamp(558_403_200)));
+    assert_eq!((offline.read_at, offline.confirmed_at, offline.is_historic), (None, None, false));
+    assert_eq!(offline.other["helpLink"], "https://www.loxone.com/help/tree-extension");
+
+    let read = &message_center.entries[1];
+    assert_eq!((read.read_at, read.desc.as_deref()), (Some(LoxoneTimestamp(558_320_000)), None));
+    let historic = &message_center.entries[2];
+    assert_eq!((historic.confirmed_at, historic.is_historic), (Some(LoxoneTimestamp(556_100_000)), true));
+    assert_eq!(message_center.entries[4].last_seen(), None);
+}
+
+#[test]
+fn maps_severities() {
+    let severities: Vec<LoxoneMessageSeverity> = message_center().entries.iter().map(|entry| entry.severity).collect();
+    assert_eq!(severities, [
+        LoxoneMessageSeverity::Error,
+        LoxoneMessageSeverity::Warning,
+        LoxoneMessageSeverity::Info,
+        LoxoneMessageSeverity::SystemError,
+        LoxoneMessageSeverity::Unknown(7),
+    ]);
+    assert!(LoxoneMessageSeverity::Info < LoxoneMessageSeverity::Warning && LoxoneMessageSeverity::Error < LoxoneMessageSeverity::SystemError);
+    assert_eq!(serde_json::to_value(&severities).unwrap(), serde_json::json!([3, 2, 1, 4, 7]));
+}
+
+#[test]
+fn filters_active_and_unread_entries() {
+    let message_center = message_center();
+    assert_eq!(titles(message_center.active()), ["Extension offline", "Battery low", "SD card error", "Unknown message"]);
+    assert_eq!(titles(message_center.unread()), ["Extension offline", "SD card error", "Unknown message"]);
+}
+
+#[test]
+fn decodes_message_center_states() {
+    let entries = serde_json::to_string(&message_center().entries).unwrap();
+    for text in &[MESSAGE_CENTER, entries.as_str()] {
+        match decode_message_center_text_state(text).unwrap() {
+            LoxoneTextState::MessageCenter(decoded) => assert_eq!(decoded.entries, message_center().entries),
+            state => panic!("unexpected {:?}", state),
+        }
+    }
+    assert!(decode_message_center_text_state(r#"{"entries": [{"severity": 1}]}"#).is_err());
+}
+
+#[test]
+fn builds_commands() {
+    assert_eq!(LoxoneMessage::confirm("0f2c4a8e-0101-1b2a-ffff504f94a0c0ff"), "confirm/0f2c4a8e-0101-1b2a-ffff504f94a0c0ff");
+    assert_eq!(LoxoneMessage::mark_read("0f2c4a8e-0101-1b2a-ffff504f94a0c0ff"), "setReadAt/0f2c4a8e-0101-1b2a-ffff504f94a0c0ff");
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "mock")]
+
+use futures_util::StreamExt;
+
+use loxone::{Secret, WebSocket};
+use loxone::loxapp3::LoxoneState;
+use loxone::mock::MockMiniserver;
+
+const LOXAPP3: &str = include_str!("fixtures/LoxAPP3.json");
+const SWITCH: &str = "10004444-0a04-0c04-ffff504f94a00004";
+const SWITCH_ACTIVE: &str = "10003333-0a03-0c03-ffff504f94a00003";
+
+#[tokio::test]
+async fn authenticates_and_exchanges_states_with_miniserver() {
+    let mut miniserver = MockMiniserver::start(serde_json::from_str(LOXAPP3).unwrap(), "admin", "secret").await.unwrap();
+    miniserver.set_state(SWITCH_ACTIVE, LoxoneState::Value(0.0));
+
+    let (mut ws, _resp, rx, recv_loop) = WebSocket::connect(miniserver.url()).await.unwrap();
+    tokio::spawn(recv_loop);
+    ws.key_exchange(miniserver.cert()).await.unwrap();
+    let jwt = ws.get_jwt("admin", &Secret::new(String::from("secret")), 4, "0f2c4a8e-0041-1b2a-ffff504f94a0c0ff", "test").await.unwrap();
+    assert_eq!(jwt.token_rights, 4);
+    ws.authenticate(&jwt.token).await.unwrap();
+
+    let (states, events) = ws.enable_status_update(rx).await.unwrap();
+    assert_eq!(states[SWITCH_ACTIVE], LoxoneState::Value(0.0));
+    futures_util::pin_mut!(events);
+    miniserver.set_state(SWITCH_ACTIVE, LoxoneState::Value(1.0));
+    assert_eq!(events.next().await, Some((SWITCH_ACTIVE.to_owned(), LoxoneState::Value(1.0))));
+
+    ws.send_io_cmd(&SWITCH.to_owned(), String::from("on")).await.unwrap();
+    assert_eq!(miniserver.next_io_cmd().await, Some((SWITCH.to_owned(), String::from("on"))));
+}
+
+#[tokio::test]
+async fn rejects_wrong_password() {
+    let miniserver = MockMiniserver::start(serde_json::from_str(LOXAPP3).unwrap(), "admin", "secret").await.unwrap();
+    let (mut ws, _resp, _rx, recv_loop) = WebSocket::connect(miniserver.url()).await.unwrap();
+    tokio::spawn(recv_loop);
+    ws.key_exchange(miniserver.cert()).await.unwrap();
+    assert!(ws.get_jwt("admin", &Secret::new(String::from("wrong")), 4, "0f2c4a8e-0041-1b2a-ffff504f94a0c0ff", "test").await.is_err());
+}
@@ -0,0 +1,51 @@
+use chrono::{NaiveDate, Weekday};
+
+use loxone::loxapp3::modes::{LoxoneCalendarEntry, LoxoneCalendarMode};
+
+#[test]
+fn encodes_entry_name() {
+    let cal_mode = LoxoneCalendarMode::Easter { offset: -2 };
+    assert_eq!(LoxoneCalendarEntry::create("Good Friday/Easter", 3, &cal_mode), "calendarcreateentry/Good%20Friday%2FEaster/3/1/-2");
+    assert_eq!(
+        LoxoneCalendarEntry::update("0f2c4a8e-0041-1b2a-ffff504f94a0c0ff", "Büro", 3, &cal_mode),
+        "calendarupdateentry/0f2c4a8e-0041-1b2a-ffff504f94a0c0ff/B%C3%BCro/3/1/-2",
+    );
+}
+
+#[test]
+fn decodes_calendar_modes() {
+    let entries: Vec<LoxoneCalendarEntry> = serde_json::from_value(serde_json::json!([
+        { "uuid": "0f2c4a8e-0041-1b2a-ffff504f94a0c0ff", "name": "Christmas", "operatingMode": 5, "calMode": 0, "month": 12, "day": 24 },
+        { "uuid": "0f2c4a8e-0042-1b2a-ffff504f94a0c0ff", "name": "Easter Monday", "operatingMode": 5, "calMode": 1, "eventOffset": 1 },
+        { "uuid": "0f2c4a8e-0043-1b2a-ffff504f94a0c0ff", "name": "Party", "operatingMode": 2, "calMode": 2, "startDate": "2026-10-31" },
+        { "uuid": "0f2c4a8e-0044-1b2a-ffff504f94a0c0ff", "name": "Vacation", "operatingMode": 1, "calMode": 3, "startDate": "2026-08-01", "endDate": "2026-08-15" },
+        { "uuid": "0f2c4a8e-0045-1b2a-ffff504f94a0c0ff", "name": "Mother's Day", "operatingMode": 5, "calMode": 4, "weekday": 6, "nth": 2, "month": 5 },
+    ])).unwrap();
+    let modes: Vec<LoxoneCalendarMode> = entries.iter().map(|entry| entry.cal_mode).collect();
+    assert_eq!(modes, vec![
+        LoxoneCalendarMode::Yearly { month: 12, day: 24 },
+        LoxoneCalendarMode::Easter { offset: 1 },
+        LoxoneCalendarMode::Date(NaiveDate::from_ymd_opt(2026, 10, 31).unwrap()),
+        LoxoneCalendarMode::Period { start: NaiveDate::from_ymd_opt(2026, 8, 1).unwrap(), end: NaiveDate::from_ymd_opt(2026, 8, 15).unwrap() },
+        LoxoneCalendarMode::Weekday { weekday: Weekday::Sun, nth: 2, month: 5 },
+    ]);
+    assert!(entries.iter().all(|entry| entry.other.is_empty()));
+}
+
+#[test]
+fn rejects_incomplete_calendar_mode() {
+    let entry = serde_json::from_value::<LoxoneCalendarEntry>(serde_json::json!({
+        "uuid": "0f2c4a8e-0041-1b2a-ffff504f94a0c0ff", "name": "Vacation", "operatingMode": 1, "calMode": 3, "startDate": "2026-08-01",
+    }));
+    assert!(entry.unwrap_err().to_string().contains("missing endDate of calendar mode 3"));
+}
+
+#[test]
+fn serializes_calendar_mode() {
+    let entry: LoxoneCalendarEntry = serde_json::from_value(serde_json::json!({
+        "uuid": "0f2c4a8e-0044-1b2a-ffff504f94a0c0ff", "name": "Vacation", "operatingMode": 1, "calMode": 3, "startDate": "2026-08-01", "endDate": "2026-08-15",
+    })).unwrap();
+    assert_eq!(serde_json::to_value(&entry).unwrap(), serde_json::json!({
+        "uuid": "0f2c4a8e-0044-1b2a-ffff504f94a0c0ff", "name": "Vacation", "operatingMode": 1, "calMode": 3, "startDate": "2026-08-01", "endDate": "2026-08-15",
+    }));
+}